@@ -0,0 +1,224 @@
+use cairo_m::bench_cairo_m;
+use stwo_prover::core::fields::m31::{M31, P};
+use utils::{
+    bench::{benchmark, Metrics, Target},
+    metadata::POSEIDON2_INPUTS,
+    params::{Params, SIZE},
+    poseidon2_params,
+};
+
+const WIDTH: usize = 16;
+const FULL_ROUNDS: usize = 8;
+const PARTIAL_ROUNDS: usize = 14;
+
+/// Shifts of the powers of two in the diagonal of the internal matrix minus the identity, after
+/// its first element `-2`.
+const INTERNAL_SHIFTS: [u32; WIDTH - 1] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 10, 12, 13, 14, 15, 16];
+
+/// Round constants of the full rounds and of the partial rounds, and diagonal of the internal
+/// matrix.
+struct Constants {
+    external: Vec<M31>,
+    internal: Vec<M31>,
+    diagonal: Vec<M31>,
+}
+
+impl Constants {
+    fn new() -> Self {
+        let mut diagonal = vec![-M31(2)];
+        diagonal.extend(INTERNAL_SHIFTS.map(|shift| M31(1 << shift)));
+        Constants {
+            external: random_elements(FULL_ROUNDS * WIDTH, 0x706f_7365_6964_6f6e),
+            internal: random_elements(PARTIAL_ROUNDS, 0x706f_7365_6964_6f6f),
+            diagonal,
+        }
+    }
+}
+
+/// Reference implementation of the Poseidon2 permutation of [`cairo_m_source`], applied `n` times
+/// to the zero state.
+fn poseidon2_chain(n: u32) -> u32 {
+    let constants = Constants::new();
+    let mut state = [M31(0); WIDTH];
+    for _ in 0..n {
+        external_layer(&mut state);
+        for round in 0..FULL_ROUNDS {
+            if round == FULL_ROUNDS / 2 {
+                for &constant in &constants.internal {
+                    state[0] = sbox(state[0] + constant);
+                    let sum = state.iter().fold(M31(0), |sum, &element| sum + element);
+                    for (element, &diagonal) in state.iter_mut().zip(&constants.diagonal) {
+                        *element = *element * diagonal + sum;
+                    }
+                }
+            }
+            for (i, element) in state.iter_mut().enumerate() {
+                *element = sbox(*element + constants.external[round * WIDTH + i]);
+            }
+            external_layer(&mut state);
+        }
+    }
+    state[0].0
+}
+
+/// Start of the Cairo-M program, up to the variables of the chain.
+const PRELUDE: &str = "\
+// Poseidon2 permutation over M31 (`p = 2^31 - 1`), with a width of 16, an `x^5` S-box, 8 full
+// rounds and 14 partial rounds, unrolled over the 16 elements of the state.
+//
+// The external matrix is the one of the Poseidon2 paper and the internal diagonal the one of
+// Plonky3. The round constants are drawn from the SplitMix64 generator used by
+// `guests::poseidon2`, so digests do not match other instances.
+
+func sbox(x: felt) -> felt {
+    let x2 = x * x;
+    let x4 = x2 * x2;
+    return x4 * x;
+}
+
+// Applies the permutation n times to the zero state, and returns the first element.
+func poseidon2_chain(n: felt) -> felt {";
+
+/// Cairo-M program computing [`poseidon2_chain`]. Cairo-M has no arrays, so the permutation is
+/// unrolled over 16 variables holding the state, with the round constants inlined.
+fn cairo_m_source() -> String {
+    let constants = Constants::new();
+    let mut lines: Vec<String> = PRELUDE.lines().map(String::from).collect();
+    let variables = (0..WIDTH)
+        .map(|i| format!("s{}", i))
+        .chain((0..6).map(|i| format!("t{}", i)))
+        .chain((0..4).map(|i| format!("u{}", i)))
+        .chain(["i".to_string()]);
+    lines.extend(variables.map(|variable| format!("    let {} = 0;", variable)));
+    lines.push("    while (i != n) {".to_string());
+
+    let mut body = vec!["// Initial external layer.".to_string()];
+    external_layer_source(&mut body);
+    for round in 0..FULL_ROUNDS {
+        if round == FULL_ROUNDS / 2 {
+            for (partial_round, constant) in constants.internal.iter().enumerate() {
+                body.push(format!("// Partial round {}.", partial_round));
+                body.push(format!("s0 = sbox(s0 + {});", constant.0));
+                let sum = (0..WIDTH).map(|i| format!("s{}", i)).collect::<Vec<_>>();
+                body.push(format!("u0 = {};", sum.join(" + ")));
+                for (i, diagonal) in constants.diagonal.iter().enumerate() {
+                    body.push(format!("s{} = s{} * {} + u0;", i, i, diagonal.0));
+                }
+            }
+        }
+        body.push(format!("// Full round {}.", round));
+        for i in 0..WIDTH {
+            let constant = constants.external[round * WIDTH + i];
+            body.push(format!("s{} = sbox(s{} + {});", i, i, constant.0));
+        }
+        external_layer_source(&mut body);
+    }
+    body.push("i = i + 1;".to_string());
+    lines.extend(body.iter().map(|line| format!("        {}", line)));
+
+    lines.extend(["    }", "    return s0;", "}", ""].map(String::from));
+    lines.join("\n")
+}
+
+/// Statements of [`external_layer`] on the state variables, using `t0` to `t5` and `u0` to `u3`
+/// as temporaries.
+fn external_layer_source(body: &mut Vec<String>) {
+    for chunk in 0..WIDTH / 4 {
+        let [a, b, c, d] = [0, 1, 2, 3].map(|i| format!("s{}", 4 * chunk + i));
+        body.extend([
+            format!("t0 = {} + {};", a, b),
+            format!("t1 = {} + {};", c, d),
+            format!("t2 = {} + {} + t1;", b, b),
+            format!("t3 = {} + {} + t0;", d, d),
+            "t4 = t1 * 4 + t3;".to_string(),
+            "t5 = t0 * 4 + t2;".to_string(),
+            format!("{} = t3 + t5;", a),
+            format!("{} = t5;", b),
+            format!("{} = t2 + t4;", c),
+            format!("{} = t4;", d),
+        ]);
+    }
+    for j in 0..4 {
+        body.push(format!(
+            "u{} = s{} + s{} + s{} + s{};",
+            j,
+            j,
+            j + 4,
+            j + 8,
+            j + 12
+        ));
+    }
+    for i in 0..WIDTH {
+        body.push(format!("s{} = s{} + u{};", i, i, i % 4));
+    }
+}
+
+/// Multiplies the state by `circ(2 * M4, M4, ..., M4)`, with the 4x4 matrix of the Poseidon2
+/// paper.
+fn external_layer(state: &mut [M31; WIDTH]) {
+    for x in state.chunks_exact_mut(4) {
+        let t0 = x[0] + x[1];
+        let t1 = x[2] + x[3];
+        let t2 = x[1] + x[1] + t1;
+        let t3 = x[3] + x[3] + t0;
+        let t4 = t1 * M31(4) + t3;
+        let t5 = t0 * M31(4) + t2;
+        x.copy_from_slice(&[t3 + t5, t5, t2 + t4, t4]);
+    }
+    let mut sums = [M31(0); 4];
+    for (i, &element) in state.iter().enumerate() {
+        sums[i % 4] += element;
+    }
+    for (i, element) in state.iter_mut().enumerate() {
+        *element += sums[i % 4];
+    }
+}
+
+fn sbox(x: M31) -> M31 {
+    let x2 = x * x;
+    x2 * x2 * x
+}
+
+/// Draws field elements from the SplitMix64 generator of `guests::poseidon2`, rejecting the values
+/// above `p`.
+fn random_elements(n: usize, seed: u64) -> Vec<M31> {
+    let mut elements = Vec::with_capacity(n);
+    let mut state = seed;
+    while elements.len() < n {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        let element = (z >> 33) as u32;
+        if element < P {
+            elements.push(M31(element));
+        }
+    }
+    elements
+}
+
+fn bench_cairo_poseidon2(params: Params) -> Metrics {
+    let n = params.u64(SIZE) as u32;
+    bench_cairo_m(
+        Metrics::new(params),
+        "poseidon2.cm",
+        cairo_m_source(),
+        "poseidon2_chain",
+        n,
+        poseidon2_chain(n),
+    )
+}
+
+fn main() {
+    dotenv::dotenv().ok();
+    benchmark(
+        bench_cairo_poseidon2,
+        poseidon2_params(&POSEIDON2_INPUTS, "poseidon2", "m31"),
+        Target::from_args(
+            "poseidon2",
+            "cairo-m",
+            "../.outputs/benchmark/poseidon2_cairo-m.csv",
+        ),
+    );
+}
@@ -0,0 +1,78 @@
+use cairo_m_compiler::{compile_cairo, CompilerOptions};
+use cairo_m_prover::{
+    adapter::import_from_runner_output, prover::prove_cairo_m, prover_config::REGULAR_96_BITS,
+    verifier::verify_cairo_m,
+};
+use cairo_m_runner::run_cairo_program;
+use std::time::Instant;
+use stwo_prover::core::{fields::m31::M31, vcs::blake2_merkle::Blake2sMerkleChannel};
+use utils::bench::Metrics;
+
+/// Compiles the Cairo-M program `source_text`, named `source_path` in diagnostics, then runs,
+/// proves and verifies its `entrypoint_name` function on `n`, checking that it returns `expected`.
+pub fn bench_cairo_m(
+    mut metrics: Metrics,
+    source_path: &str,
+    source_text: String,
+    entrypoint_name: &str,
+    n: u32,
+    expected: u32,
+) -> Metrics {
+    // Compile the program
+    let options = CompilerOptions { verbose: false };
+    let output = compile_cairo(source_text, source_path.to_string(), options)
+        .unwrap_or_else(|_| panic!("Failed to compile {}", source_path));
+    let compiled_program = (*output.program).clone();
+
+    // Program Execution - Trace Generation
+    let entrypoint = compiled_program
+        .get_entrypoint(entrypoint_name)
+        .unwrap_or_else(|| panic!("Entrypoint {} not found", entrypoint_name));
+
+    let runner_inputs: Vec<M31> = [M31::from(n)]
+        .iter()
+        .take(entrypoint.args.len())
+        .copied()
+        .collect();
+
+    let start = Instant::now();
+    let runner_output = run_cairo_program(
+        &compiled_program,
+        entrypoint_name,
+        &runner_inputs,
+        Default::default(),
+    )
+    .expect("failed to run cairo program");
+    metrics.exec_duration = start.elapsed();
+
+    // Return values
+    let return_values: Vec<u32> = runner_output
+        .return_values
+        .iter()
+        .map(|value| value.0)
+        .collect();
+    assert_eq!(expected, return_values[0]);
+
+    // Metrics Computation
+    metrics.cycles = runner_output.vm.trace.len() as u64;
+
+    // Proof Generation
+    let mut prover_input =
+        import_from_runner_output(runner_output).expect("failed to import from runner output");
+
+    let pcs_config = REGULAR_96_BITS;
+
+    let start = Instant::now();
+    let proof = prove_cairo_m::<Blake2sMerkleChannel>(&mut prover_input, Some(pcs_config))
+        .expect("failed to generate proof");
+    metrics.proof_duration = start.elapsed();
+    metrics.proof_bytes = proof.stark_proof.size_estimate();
+
+    // verify proof
+    let start = Instant::now();
+    verify_cairo_m::<Blake2sMerkleChannel>(proof, Some(pcs_config))
+        .expect("failed to verify proof");
+    metrics.verify_duration = start.elapsed();
+
+    metrics
+}
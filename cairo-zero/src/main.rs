@@ -1,7 +1,7 @@
 use std::{path::Path, time::Instant};
 
 use utils::{
-    bench::{benchmark, Metrics},
+    bench::{benchmark, Metrics, Target},
     metadata::FIBONACCI_INPUTS,
 };
 
@@ -41,6 +41,7 @@ fn bench_cairo_fib(n: u32) -> Metrics {
     let entrypoint = "main";
     let program = Program::from_file(Path::new(&path), Some(entrypoint))
         .expect("Failed to read Cairo Zero program");
+    metrics.program_bytes = std::fs::metadata(&path).map_or(0, |m| m.len() as usize);
 
     let config = CairoRunConfig {
         entrypoint: "main",
@@ -88,6 +89,10 @@ fn main() {
     benchmark(
         bench_cairo_fib,
         &FIBONACCI_INPUTS,
-        "../.outputs/benchmark/fib_cairo-zero.csv",
+        Target::from_args(
+            "fib",
+            "cairo-zero",
+            "../.outputs/benchmark/fib_cairo-zero.csv",
+        ),
     );
 }
@@ -0,0 +1,44 @@
+use cairo::bench_cairo;
+use cairo_vm::Felt252;
+use starknet_types_core::hash::Poseidon;
+use utils::{
+    bench::{benchmark, Metrics, Target},
+    metadata::POSEIDON2_INPUTS,
+    params::{Params, SIZE},
+    poseidon2_params,
+};
+
+/// Rust reference of `test_data/src/poseidon2.cairo`: the first element of the state after `n`
+/// Poseidon permutations of the zero state.
+fn hash_chain(n: u32) -> Felt252 {
+    let mut state = [Felt252::ZERO; 3];
+    for _ in 0..n {
+        Poseidon::hades_permutation(&mut state);
+    }
+    state[0]
+}
+
+fn bench_cairo_poseidon2(params: Params) -> Metrics {
+    let n = params.u64(SIZE) as u32;
+    bench_cairo(
+        Metrics::new(params),
+        "test_data/target/release/poseidon2.executable.json",
+        n,
+        hash_chain(n),
+    )
+}
+
+/// Runs a compiled Cairo program and generate a proof of execution.
+fn main() {
+    dotenv::dotenv().ok();
+    benchmark(
+        bench_cairo_poseidon2,
+        // Cairo has no Poseidon2, its builtin computes the Poseidon permutation.
+        poseidon2_params(&POSEIDON2_INPUTS, "poseidon", "stark252"),
+        Target::from_args(
+            "poseidon2",
+            "cairo",
+            "../.outputs/benchmark/poseidon2_cairo.csv",
+        ),
+    );
+}
@@ -1 +1,2 @@
 mod fibonacci;
+mod poseidon2;
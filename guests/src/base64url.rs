@@ -0,0 +1,51 @@
+//! Base64url encoding without padding (RFC 4648, section 5), as used by WebAuthn and JWTs.
+
+extern crate alloc;
+use alloc::{string::String, vec::Vec};
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+pub fn encode(data: &[u8]) -> String {
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let bits = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        // A chunk of n bytes is encoded by n + 1 characters.
+        for i in 0..=chunk.len() {
+            encoded.push(ALPHABET[(bits >> (18 - 6 * i) & 0x3f) as usize] as char);
+        }
+    }
+    encoded
+}
+
+/// Decodes unpadded base64url, or returns `None` if `encoded` has an invalid length or character.
+pub fn decode(encoded: &str) -> Option<Vec<u8>> {
+    if encoded.len() % 4 == 1 {
+        return None;
+    }
+    let mut decoded = Vec::with_capacity(encoded.len() / 4 * 3 + 2);
+    for chunk in encoded.as_bytes().chunks(4) {
+        let mut bits = 0;
+        for (i, &character) in chunk.iter().enumerate() {
+            bits |= (value(character)? as u32) << (18 - 6 * i);
+        }
+        // A chunk of n characters encodes n - 1 bytes.
+        decoded.extend_from_slice(&bits.to_be_bytes()[1..chunk.len()]);
+    }
+    Some(decoded)
+}
+
+fn value(character: u8) -> Option<u8> {
+    match character {
+        b'A'..=b'Z' => Some(character - b'A'),
+        b'a'..=b'z' => Some(character - b'a' + 26),
+        b'0'..=b'9' => Some(character - b'0' + 52),
+        b'-' => Some(62),
+        b'_' => Some(63),
+        _ => None,
+    }
+}
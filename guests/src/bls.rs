@@ -0,0 +1,68 @@
+//! Verification of an aggregate BLS12-381 signature of a message by many signers, as done by
+//! Ethereum light clients for the sync committee: the public keys (in G1) are decompressed and
+//! summed, the message is hashed to G2, and the signature is checked with a pairing product.
+
+use bls12_381::{
+    hash_to_curve::{ExpandMsgXmd, HashToCurve},
+    multi_miller_loop, G1Affine, G1Projective, G2Affine, G2Prepared, G2Projective, Gt,
+};
+use serde::{Deserialize, Serialize};
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+/// Domain separation tag of the proof-of-possession scheme of Ethereum consensus.
+pub const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlsAggregateInput {
+    /// Compressed G1 public keys of the signers, of 48 bytes each.
+    pub public_keys: Vec<Vec<u8>>,
+    pub message: Vec<u8>,
+    /// Compressed G2 aggregate signature, of 96 bytes.
+    pub signature: Vec<u8>,
+}
+
+/// Hashes `message` to G2 with SHA-256 and the simplified SWU map, under [`DST`].
+pub fn hash_to_g2(message: &[u8]) -> G2Projective {
+    <G2Projective as HashToCurve<ExpandMsgXmd<sha2_09::Sha256>>>::hash_to_curve(message, DST)
+}
+
+/// Whether `signature` is the aggregate of the signatures of `message` by every public key, as
+/// `FastAggregateVerify` of Ethereum consensus. The public keys must not be the identity, and are
+/// assumed to have proven the possession of their secret key.
+pub fn verify_aggregate(input: &BlsAggregateInput) -> bool {
+    if input.public_keys.is_empty() {
+        return false;
+    }
+    let mut aggregate_key = G1Projective::identity();
+    for public_key in &input.public_keys {
+        let Some(public_key) = decompress_g1(public_key) else {
+            return false;
+        };
+        if bool::from(public_key.is_identity()) {
+            return false;
+        }
+        aggregate_key += public_key;
+    }
+    let Ok(signature) = <&[u8; 96]>::try_from(input.signature.as_slice()) else {
+        return false;
+    };
+    let Some(signature) = Option::<G2Affine>::from(G2Affine::from_compressed(signature)) else {
+        return false;
+    };
+
+    // e(aggregate_key, H(message)) = e(g1, signature), checked as a single product of pairings.
+    let message = G2Prepared::from(G2Affine::from(hash_to_g2(&input.message)));
+    let signature = G2Prepared::from(signature);
+    multi_miller_loop(&[
+        (&G1Affine::from(aggregate_key), &message),
+        (&-G1Affine::generator(), &signature),
+    ])
+    .final_exponentiation()
+        == Gt::identity()
+}
+
+fn decompress_g1(bytes: &[u8]) -> Option<G1Affine> {
+    G1Affine::from_compressed(bytes.try_into().ok()?).into()
+}
@@ -23,3 +23,8 @@ pub fn ecdsa_verify(input: EcdsaVerifyInput) -> bool {
         .verify(&input.message, &input.signature)
         .is_ok()
 }
+
+/// Verifies every signature of the batch.
+pub fn ecdsa_verify_batch(inputs: Vec<EcdsaVerifyInput>) -> bool {
+    inputs.into_iter().all(ecdsa_verify)
+}
@@ -0,0 +1,29 @@
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Ed25519VerifyInput {
+    pub public_key: [u8; 32],
+    pub message: Vec<u8>,
+    /// The 64 bytes of the signature, which serde only supports as a slice.
+    pub signature: Vec<u8>,
+}
+
+pub fn ed25519_verify(input: Ed25519VerifyInput) -> bool {
+    let Ok(verifying_key) = VerifyingKey::from_bytes(&input.public_key) else {
+        return false;
+    };
+    let Ok(signature) = Signature::from_slice(&input.signature) else {
+        return false;
+    };
+
+    verifying_key.verify(&input.message, &signature).is_ok()
+}
+
+/// Verifies every signature of the batch.
+pub fn ed25519_verify_batch(inputs: Vec<Ed25519VerifyInput>) -> bool {
+    inputs.into_iter().all(ed25519_verify)
+}
@@ -0,0 +1,157 @@
+//! Recovery of the sender of a signed EIP-1559 transaction, as done by an Ethereum node receiving
+//! it: the transaction is RLP-decoded, its signing hash computed with Keccak-256, and the public
+//! key of the signer recovered from the signature.
+
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+
+use crate::keccak;
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+pub const TRANSACTION_TYPE: u8 = 0x02;
+
+/// Number of fields of a signed transaction: `chain_id`, `nonce`, `max_priority_fee_per_gas`,
+/// `max_fee_per_gas`, `gas_limit`, `to`, `value`, `data`, `access_list`, then the signature
+/// `y_parity`, `r` and `s`.
+const NUM_FIELDS: usize = 12;
+/// Number of fields covered by the signature.
+const NUM_UNSIGNED_FIELDS: usize = 9;
+
+/// Returns the address of the account which signed the raw transaction
+/// `0x02 || rlp([chain_id, ..., access_list, y_parity, r, s])`, or `None` if the transaction is
+/// malformed or its signature invalid.
+///
+/// The fields are not checked to be canonically encoded: they are only hashed as they are.
+pub fn recover_sender(raw: &[u8]) -> Option<[u8; 20]> {
+    let (&transaction_type, payload) = raw.split_first()?;
+    if transaction_type != TRANSACTION_TYPE {
+        return None;
+    }
+    let (Item::List(mut list), _, rest) = split_item(payload)? else {
+        return None;
+    };
+    if !rest.is_empty() {
+        return None;
+    }
+
+    let mut fields = [(Item::String(&[]), &[][..]); NUM_FIELDS];
+    for field in &mut fields {
+        let (item, encoding, rest) = split_item(list)?;
+        *field = (item, encoding);
+        list = rest;
+    }
+    if !list.is_empty() {
+        return None;
+    }
+
+    // The signing hash is the hash of the transaction without the signature fields.
+    let unsigned_len = fields[..NUM_UNSIGNED_FIELDS]
+        .iter()
+        .map(|(_, encoding)| encoding.len())
+        .sum();
+    let mut message = Vec::with_capacity(unsigned_len + 10);
+    message.push(TRANSACTION_TYPE);
+    push_list_header(&mut message, unsigned_len);
+    for (_, encoding) in &fields[..NUM_UNSIGNED_FIELDS] {
+        message.extend_from_slice(encoding);
+    }
+    let signing_hash = keccak::keccak(&message);
+
+    let y_parity = match fields[9].0.string()? {
+        [] => 0,
+        [1] => 1,
+        _ => return None,
+    };
+    let r = scalar(fields[10].0.string()?)?;
+    let s = scalar(fields[11].0.string()?)?;
+    let signature = Signature::from_scalars(r, s).ok()?;
+    let recovery_id = RecoveryId::from_byte(y_parity)?;
+    let verifying_key =
+        VerifyingKey::recover_from_prehash(&signing_hash, &signature, recovery_id).ok()?;
+
+    Some(address(&verifying_key))
+}
+
+/// Ethereum address of a public key: the last 20 bytes of the hash of its uncompressed encoding.
+pub fn address(verifying_key: &VerifyingKey) -> [u8; 20] {
+    let point = verifying_key.to_encoded_point(false);
+    let digest = keccak::keccak(&point.as_bytes()[1..]);
+    let mut address = [0; 20];
+    address.copy_from_slice(&digest[12..]);
+    address
+}
+
+/// RLP item, with its payload.
+#[derive(Clone, Copy)]
+enum Item<'a> {
+    String(&'a [u8]),
+    List(&'a [u8]),
+}
+
+impl<'a> Item<'a> {
+    fn string(self) -> Option<&'a [u8]> {
+        match self {
+            Item::String(bytes) => Some(bytes),
+            Item::List(_) => None,
+        }
+    }
+}
+
+/// Splits the first RLP item off `data`, returning the item, its encoding and the remaining bytes.
+fn split_item(data: &[u8]) -> Option<(Item<'_>, &[u8], &[u8])> {
+    let (&prefix, tail) = data.split_first()?;
+    let (header_len, payload_len, is_list) = match prefix {
+        0x00..=0x7f => return Some((Item::String(&data[..1]), &data[..1], &data[1..])),
+        0x80..=0xb7 => (1, (prefix - 0x80) as usize, false),
+        0xb8..=0xbf => {
+            let len_len = (prefix - 0xb7) as usize;
+            (1 + len_len, read_length(tail.get(..len_len)?)?, false)
+        }
+        0xc0..=0xf7 => (1, (prefix - 0xc0) as usize, true),
+        0xf8..=0xff => {
+            let len_len = (prefix - 0xf7) as usize;
+            (1 + len_len, read_length(tail.get(..len_len)?)?, true)
+        }
+    };
+    let encoding = data.get(..header_len.checked_add(payload_len)?)?;
+    let payload = &encoding[header_len..];
+    let item = if is_list {
+        Item::List(payload)
+    } else {
+        Item::String(payload)
+    };
+    Some((item, encoding, &data[encoding.len()..]))
+}
+
+/// Reads a big-endian length, which must fit in a `usize` of the guest.
+fn read_length(bytes: &[u8]) -> Option<usize> {
+    if bytes.len() > core::mem::size_of::<usize>() {
+        return None;
+    }
+    Some(
+        bytes
+            .iter()
+            .fold(0, |len, &byte| (len << 8) | byte as usize),
+    )
+}
+
+fn push_list_header(out: &mut Vec<u8>, len: usize) {
+    if len < 56 {
+        out.push(0xc0 + len as u8);
+    } else {
+        let bytes = len.to_be_bytes();
+        let zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+        out.push(0xf7 + (bytes.len() - zeros) as u8);
+        out.extend_from_slice(&bytes[zeros..]);
+    }
+}
+
+/// Left-pads a big-endian integer of at most 32 bytes.
+fn scalar(bytes: &[u8]) -> Option<[u8; 32]> {
+    let mut scalar = [0; 32];
+    scalar
+        .get_mut(32usize.checked_sub(bytes.len())?..)?
+        .copy_from_slice(bytes);
+    Some(scalar)
+}
@@ -0,0 +1,17 @@
+//! Lookup of members of compact JSON objects, such as the client data of WebAuthn or the claims
+//! of a JWT, without parsing the whole document.
+
+extern crate alloc;
+use alloc::format;
+
+/// Value of the string member `key` of a flat JSON object serialized without whitespace. The
+/// value is returned as it is written, so it must not contain escaped characters.
+pub fn string_member<'a>(json: &'a str, key: &str) -> Option<&'a str> {
+    let pattern = format!("\"{}\":\"", key);
+    let (start, _) = json
+        .match_indices(&pattern)
+        .find(|(index, _)| !json[..*index].ends_with('\\'))?;
+    let value = &json[start + pattern.len()..];
+    let end = value.find('"')?;
+    (!value[..end].contains('\\')).then_some(&value[..end])
+}
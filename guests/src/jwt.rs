@@ -0,0 +1,61 @@
+//! Verification of an OpenID Connect ID token, as done in zkLogin-style flows to prove the
+//! possession of a token issued to a user: the JWT is split into its base64url-encoded header,
+//! claims and signature, its RSA-2048 PKCS#1 v1.5 signature over SHA-256 (`RS256`) is checked
+//! against the public key of the issuer, and the subject of the token is extracted.
+
+use rsa::{traits::PublicKeyParts, BigUint, Pkcs1v15Sign, RsaPublicKey};
+use serde::{Deserialize, Serialize};
+
+use crate::{base64url, json::string_member, sha2};
+
+extern crate alloc;
+use alloc::{string::String, vec::Vec};
+
+/// Size in bytes of the modulus of the RSA keys.
+const MODULUS_BYTES: usize = 256;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JwtInput {
+    /// Compact serialization `header.claims.signature` of the token.
+    pub token: String,
+    /// Big-endian modulus of the public key of the issuer.
+    pub modulus: Vec<u8>,
+    /// Big-endian public exponent of the public key of the issuer.
+    pub exponent: Vec<u8>,
+}
+
+/// Returns the `sub` claim of the token, or `None` if the token is malformed, is not signed with
+/// `RS256` or its signature is invalid.
+pub fn verify_token(input: &JwtInput) -> Option<String> {
+    let mut parts = input.token.split('.');
+    let (header, claims, signature) = (parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() {
+        return None;
+    }
+
+    let header = String::from_utf8(base64url::decode(header)?).ok()?;
+    if string_member(&header, "alg") != Some("RS256") {
+        return None;
+    }
+
+    let public_key = RsaPublicKey::new(
+        BigUint::from_bytes_be(&input.modulus),
+        BigUint::from_bytes_be(&input.exponent),
+    )
+    .ok()?;
+    if public_key.size() != MODULUS_BYTES {
+        return None;
+    }
+    // The signature covers the encoded header and claims, with the dot separating them.
+    let signing_input = &input.token[..input.token.len() - signature.len() - 1];
+    public_key
+        .verify(
+            Pkcs1v15Sign::new::<rsa::sha2::Sha256>(),
+            &sha2::sha2(signing_input.as_bytes()),
+            &base64url::decode(signature)?,
+        )
+        .ok()?;
+
+    let claims = String::from_utf8(base64url::decode(claims)?).ok()?;
+    string_member(&claims, "sub").map(String::from)
+}
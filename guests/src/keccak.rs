@@ -0,0 +1,8 @@
+use sha3::{Digest, Keccak256};
+
+pub fn keccak(input: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(input);
+    let result = hasher.finalize();
+    Into::<[u8; 32]>::into(result)
+}
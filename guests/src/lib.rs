@@ -1,13 +1,43 @@
-#![cfg_attr(any(feature = "fibonacci", feature = "with-sha2"), no_std)]
+#![cfg_attr(
+    any(
+        feature = "fibonacci",
+        feature = "with-sha2",
+        feature = "with-keccak",
+        feature = "with-poseidon2"
+    ),
+    no_std
+)]
 
+#[cfg(any(feature = "with-webauthn", feature = "with-jwt"))]
+pub mod base64url;
 #[cfg(feature = "fibonacci")]
 pub mod fib;
+#[cfg(any(feature = "with-webauthn", feature = "with-jwt"))]
+pub mod json;
+#[cfg(feature = "with-keccak")]
+pub mod keccak;
+#[cfg(feature = "with-merkle")]
+pub mod merkle;
+#[cfg(feature = "with-mlp")]
+pub mod mlp;
+#[cfg(feature = "with-poseidon2")]
+pub mod poseidon2;
 #[cfg(feature = "with-sha2")]
 pub mod sha2;
 
+#[cfg(feature = "with-bls")]
+pub mod bls;
 #[cfg(feature = "with-ecdsa")]
 pub mod ecdsa;
+#[cfg(feature = "with-ed25519")]
+pub mod ed25519;
+#[cfg(feature = "with-eip1559")]
+pub mod eip1559;
 #[cfg(feature = "ethtransfer")]
 pub mod ethtransfer;
+#[cfg(feature = "with-jwt")]
+pub mod jwt;
 #[cfg(feature = "evm")]
 pub mod revm;
+#[cfg(feature = "with-webauthn")]
+pub mod webauthn;
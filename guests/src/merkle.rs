@@ -0,0 +1,92 @@
+use core::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+use crate::{keccak, poseidon2, sha2};
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+/// Hash used to compress two nodes of a Merkle tree into their parent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MerkleHash {
+    Sha256,
+    Keccak,
+    Poseidon2,
+}
+
+impl MerkleHash {
+    pub fn compress(&self, left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        match self {
+            MerkleHash::Sha256 => sha2::sha2(&concat(left, right)),
+            MerkleHash::Keccak => keccak::keccak(&concat(left, right)),
+            MerkleHash::Poseidon2 => poseidon2::compress(left, right),
+        }
+    }
+}
+
+impl FromStr for MerkleHash {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sha256" => Ok(MerkleHash::Sha256),
+            "keccak" => Ok(MerkleHash::Keccak),
+            "poseidon2" => Ok(MerkleHash::Poseidon2),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Path from a leaf to the root: the sibling of each node, from the leaf up. The bits of `index`
+/// tell whether each node is a left (0) or right (1) child.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InclusionProof {
+    pub leaf: [u8; 32],
+    pub index: u64,
+    pub siblings: Vec<[u8; 32]>,
+}
+
+impl InclusionProof {
+    /// Returns the root of the tree the leaf is in, according to the path.
+    pub fn root(&self, hash: MerkleHash) -> [u8; 32] {
+        let mut node = self.leaf;
+        for (level, sibling) in self.siblings.iter().enumerate() {
+            node = if (self.index >> level) & 1 == 0 {
+                hash.compress(&node, sibling)
+            } else {
+                hash.compress(sibling, &node)
+            };
+        }
+        node
+    }
+
+    /// Whether the leaf is at its index in the tree of `root`.
+    pub fn verify(&self, hash: MerkleHash, root: &[u8; 32]) -> bool {
+        // The bits of the index above the depth of the tree are not used by the path.
+        let depth = self.siblings.len();
+        let in_tree = depth >= 64 || self.index >> depth == 0;
+        in_tree && self.root(hash) == *root
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MerkleInput {
+    pub hash: MerkleHash,
+    pub root: [u8; 32],
+    pub proofs: Vec<InclusionProof>,
+}
+
+/// Verifies that every leaf is in the tree of `root`, at its index.
+pub fn verify_batch(input: &MerkleInput) -> bool {
+    input
+        .proofs
+        .iter()
+        .all(|proof| proof.verify(input.hash, &input.root))
+}
+
+fn concat(left: &[u8; 32], right: &[u8; 32]) -> [u8; 64] {
+    let mut data = [0; 64];
+    data[..32].copy_from_slice(left);
+    data[32..].copy_from_slice(right);
+    data
+}
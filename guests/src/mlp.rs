@@ -0,0 +1,146 @@
+//! Inference of a small quantized multilayer perceptron, as run by on-device ML models: an int8
+//! input goes through two hidden layers of `width` neurons with ReLU activations, and an output
+//! layer giving one int32 logit per class. Products of int8 weights and activations are
+//! accumulated in int32, then shifted back to int8 between layers.
+//!
+//! The weights and biases are generated at compile time from a fixed seed, and embedded in the
+//! program. A model of width `width` uses the first `width` rows and columns of each matrix, so
+//! that every width runs with the same tables. Only integer arithmetic is used, so that the
+//! logits are the same on every target, including wasm32.
+
+use serde::{Deserialize, Serialize};
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+/// Number of int8 values of the input, e.g. an 8x8 grayscale image.
+pub const INPUT_LEN: usize = 64;
+pub const NUM_LOGITS: usize = 10;
+/// Largest width of the hidden layers.
+pub const MAX_WIDTH: usize = 256;
+
+static HIDDEN1_WEIGHTS: [i8; MAX_WIDTH * INPUT_LEN] = weights(1);
+static HIDDEN1_BIASES: [i32; MAX_WIDTH] = biases(2);
+static HIDDEN2_WEIGHTS: [i8; MAX_WIDTH * MAX_WIDTH] = weights(3);
+static HIDDEN2_BIASES: [i32; MAX_WIDTH] = biases(4);
+static OUTPUT_WEIGHTS: [i8; NUM_LOGITS * MAX_WIDTH] = weights(5);
+static OUTPUT_BIASES: [i32; NUM_LOGITS] = biases(6);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MlpInput {
+    /// Width of the hidden layers, at most [`MAX_WIDTH`].
+    pub width: usize,
+    /// Input vector of [`INPUT_LEN`] values.
+    pub input: Vec<i8>,
+}
+
+/// Logits of the model of width `input.width` for the input vector.
+pub fn infer(input: &MlpInput) -> [i32; NUM_LOGITS] {
+    let width = input.width;
+    assert!(
+        (1..=MAX_WIDTH).contains(&width),
+        "width must be between 1 and {}",
+        MAX_WIDTH
+    );
+    assert_eq!(input.input.len(), INPUT_LEN, "invalid input length");
+
+    let mut hidden1 = [0; MAX_WIDTH];
+    for (row, activation) in hidden1[..width].iter_mut().enumerate() {
+        let weights = &HIDDEN1_WEIGHTS[row * INPUT_LEN..][..INPUT_LEN];
+        *activation = requantize(dot(weights, &input.input) + HIDDEN1_BIASES[row], INPUT_LEN);
+    }
+    let mut hidden2 = [0; MAX_WIDTH];
+    for (row, activation) in hidden2[..width].iter_mut().enumerate() {
+        let weights = &HIDDEN2_WEIGHTS[row * MAX_WIDTH..][..width];
+        *activation = requantize(dot(weights, &hidden1[..width]) + HIDDEN2_BIASES[row], width);
+    }
+    let mut logits = [0; NUM_LOGITS];
+    for (row, logit) in logits.iter_mut().enumerate() {
+        let weights = &OUTPUT_WEIGHTS[row * MAX_WIDTH..][..width];
+        *logit = dot(weights, &hidden2[..width]) + OUTPUT_BIASES[row];
+    }
+    logits
+}
+
+/// Index of the largest logit, the first one on ties.
+pub fn predict(input: &MlpInput) -> usize {
+    let logits = infer(input);
+    (0..NUM_LOGITS).fold(0, |best, class| {
+        if logits[class] > logits[best] {
+            class
+        } else {
+            best
+        }
+    })
+}
+
+/// FNV-1a hash of the little-endian bytes of the logits, for the targets which return a single
+/// value.
+pub fn logits_digest(logits: &[i32; NUM_LOGITS]) -> u64 {
+    logits
+        .iter()
+        .flat_map(|logit| logit.to_le_bytes())
+        .fold(0xcbf29ce484222325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        })
+}
+
+/// Input vector drawn from a fixed seed, for the targets which cannot be given a vector.
+pub fn sample_input() -> Vec<i8> {
+    let mut state = 7;
+    (0..INPUT_LEN)
+        .map(|_| {
+            state = next(state);
+            (state >> 57) as i8
+        })
+        .collect()
+}
+
+fn dot(weights: &[i8], activations: &[i8]) -> i32 {
+    weights
+        .iter()
+        .zip(activations)
+        .map(|(&weight, &activation)| weight as i32 * activation as i32)
+        .sum()
+}
+
+/// ReLU, then scaling of an accumulator of `fan_in` products back to int8 with rounding. The
+/// divisor grows with the square root of `fan_in`, like the magnitude of a sum of random products.
+fn requantize(accumulator: i32, fan_in: usize) -> i8 {
+    let shift = 7 + fan_in.ilog2().div_ceil(2);
+    let scaled = (accumulator.max(0) + (1 << (shift - 1))) >> shift;
+    scaled.min(i8::MAX as i32) as i8
+}
+
+/// Output function of SplitMix64, iterated on its own output as a simple generator.
+const fn next(state: u64) -> u64 {
+    let mut z = state.wrapping_add(0x9e3779b97f4a7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+const fn weights<const N: usize>(seed: u64) -> [i8; N] {
+    let mut weights = [0; N];
+    let mut state = seed;
+    let mut i = 0;
+    while i < N {
+        state = next(state);
+        weights[i] = (state >> 56) as i8;
+        i += 1;
+    }
+    weights
+}
+
+/// Biases of magnitude at most 2^11, small next to the accumulated products.
+const fn biases<const N: usize>(seed: u64) -> [i32; N] {
+    let mut biases = [0; N];
+    let mut state = seed;
+    let mut i = 0;
+    while i < N {
+        state = next(state);
+        biases[i] = (state >> 52) as i32 - (1 << 11);
+        i += 1;
+    }
+    biases
+}
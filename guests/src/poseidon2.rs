@@ -0,0 +1,178 @@
+//! Poseidon2 permutation over BabyBear (`p = 2^31 - 2^27 + 1`), with a width of 16, an `x^7`
+//! S-box, 8 full rounds and 13 partial rounds.
+//!
+//! The external matrix is the one of the Poseidon2 paper and the internal diagonal the one of
+//! Plonky3. The round constants are drawn from a fixed seed, so digests do not match other
+//! instances, but the cost of a permutation does.
+
+pub const WIDTH: usize = 16;
+
+const P: u32 = 0x7800_0001;
+const FULL_ROUNDS: usize = 8;
+const PARTIAL_ROUNDS: usize = 13;
+
+const EXTERNAL_CONSTANTS: [u32; FULL_ROUNDS * WIDTH] = random_elements(0x706f_7365_6964_6f6e);
+const INTERNAL_CONSTANTS: [u32; PARTIAL_ROUNDS] = random_elements(0x706f_7365_6964_6f6f);
+
+/// Diagonal of the internal matrix minus the identity:
+/// `[-2, 1, 2, 1/2, 3, 4, -1/2, -3, -4, 1/2^8, 1/4, 1/8, 1/2^27, -1/2^8, -1/16, -1/2^27]`.
+const INTERNAL_DIAGONAL: [u32; WIDTH] = [
+    neg(2),
+    1,
+    2,
+    inv(2),
+    3,
+    4,
+    neg(inv(2)),
+    neg(3),
+    neg(4),
+    inv(1 << 8),
+    inv(4),
+    inv(8),
+    inv(1 << 27),
+    neg(inv(1 << 8)),
+    neg(inv(16)),
+    neg(inv(1 << 27)),
+];
+
+pub fn permute(state: &mut [u32; WIDTH]) {
+    external_layer(state);
+    for round in 0..FULL_ROUNDS {
+        if round == FULL_ROUNDS / 2 {
+            for constant in INTERNAL_CONSTANTS {
+                state[0] = sbox(add(state[0], constant));
+                internal_layer(state);
+            }
+        }
+        for (i, element) in state.iter_mut().enumerate() {
+            *element = sbox(add(*element, EXTERNAL_CONSTANTS[round * WIDTH + i]));
+        }
+        external_layer(state);
+    }
+}
+
+/// Applies the permutation `n` times to the zero state, and returns the first element.
+pub fn hash_chain(n: u32) -> u32 {
+    let mut state = [0; WIDTH];
+    for _ in 0..n {
+        permute(&mut state);
+    }
+    state[0]
+}
+
+/// Compresses two 32-byte nodes into one: each node is read as 8 little-endian words reduced
+/// modulo `p`, and the output is the first half of the permuted state.
+pub fn compress(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut state = [0; WIDTH];
+    for (element, word) in state
+        .iter_mut()
+        .zip(left.chunks_exact(4).chain(right.chunks_exact(4)))
+    {
+        *element = u32::from_le_bytes(word.try_into().unwrap()) % P;
+    }
+    permute(&mut state);
+
+    let mut output = [0; 32];
+    for (bytes, element) in output.chunks_exact_mut(4).zip(state) {
+        bytes.copy_from_slice(&element.to_le_bytes());
+    }
+    output
+}
+
+/// Multiplies the state by `circ(2 * M4, M4, ..., M4)`.
+fn external_layer(state: &mut [u32; WIDTH]) {
+    for chunk in state.chunks_exact_mut(4) {
+        mds_4(chunk.try_into().unwrap());
+    }
+    let mut sums = [0; 4];
+    for (i, element) in state.iter().enumerate() {
+        sums[i % 4] = add(sums[i % 4], *element);
+    }
+    for (i, element) in state.iter_mut().enumerate() {
+        *element = add(*element, sums[i % 4]);
+    }
+}
+
+/// Multiplies by the 4x4 matrix of the Poseidon2 paper:
+/// `[[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]]`.
+fn mds_4(x: &mut [u32; 4]) {
+    let t0 = add(x[0], x[1]);
+    let t1 = add(x[2], x[3]);
+    let t2 = add(add(x[1], x[1]), t1);
+    let t3 = add(add(x[3], x[3]), t0);
+    let t4 = add(mul(t1, 4), t3);
+    let t5 = add(mul(t0, 4), t2);
+    let t6 = add(t3, t5);
+    let t7 = add(t2, t4);
+    *x = [t6, t5, t7, t4];
+}
+
+/// Multiplies the state by `1 + diag(INTERNAL_DIAGONAL)`.
+fn internal_layer(state: &mut [u32; WIDTH]) {
+    let sum = state.iter().fold(0, |sum, element| add(sum, *element));
+    for (element, diagonal) in state.iter_mut().zip(INTERNAL_DIAGONAL) {
+        *element = add(mul(*element, diagonal), sum);
+    }
+}
+
+fn sbox(x: u32) -> u32 {
+    let x2 = mul(x, x);
+    let x3 = mul(x2, x);
+    let x4 = mul(x2, x2);
+    mul(x3, x4)
+}
+
+const fn add(a: u32, b: u32) -> u32 {
+    let sum = a + b;
+    if sum >= P {
+        sum - P
+    } else {
+        sum
+    }
+}
+
+const fn mul(a: u32, b: u32) -> u32 {
+    ((a as u64 * b as u64) % P as u64) as u32
+}
+
+const fn neg(a: u32) -> u32 {
+    if a == 0 {
+        0
+    } else {
+        P - a
+    }
+}
+
+const fn inv(a: u32) -> u32 {
+    let mut result = 1;
+    let mut base = a;
+    let mut exponent = P - 2;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul(result, base);
+        }
+        base = mul(base, base);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Draws field elements from a SplitMix64 generator, rejecting the values above `p`.
+const fn random_elements<const N: usize>(seed: u64) -> [u32; N] {
+    let mut elements = [0; N];
+    let mut state = seed;
+    let mut i = 0;
+    while i < N {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        let element = (z >> 33) as u32;
+        if element < P {
+            elements[i] = element;
+            i += 1;
+        }
+    }
+    elements
+}
@@ -0,0 +1,64 @@
+//! Verification of a WebAuthn assertion, the signature of a challenge by a passkey: the relying
+//! party checks the authenticator data and the client data, and the P-256 ECDSA signature of
+//! `authenticator_data || sha256(client_data_json)`.
+
+use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use serde::{Deserialize, Serialize};
+
+use crate::{base64url, json::string_member, sha2};
+
+extern crate alloc;
+use alloc::{string::String, vec::Vec};
+
+/// Flag of the authenticator data telling that the user was present.
+const USER_PRESENT: u8 = 0x01;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebAuthnAssertion {
+    /// SEC1 encoding of the P-256 public key of the passkey.
+    pub public_key: Vec<u8>,
+    /// Identifier of the relying party, whose hash starts the authenticator data.
+    pub rp_id: String,
+    /// Challenge sent by the relying party, which the client data holds in base64url.
+    pub challenge: Vec<u8>,
+    pub authenticator_data: Vec<u8>,
+    pub client_data_json: String,
+    /// DER encoding of the signature.
+    pub signature: Vec<u8>,
+}
+
+/// Whether the assertion is a valid signature of the challenge for the relying party.
+pub fn verify_assertion(assertion: &WebAuthnAssertion) -> bool {
+    // The authenticator data starts with the hash of the relying party identifier, the flags and
+    // a 4-byte signature counter.
+    let authenticator_data = &assertion.authenticator_data;
+    if authenticator_data.len() < 37
+        || authenticator_data[..32] != sha2::sha2(assertion.rp_id.as_bytes())
+        || authenticator_data[32] & USER_PRESENT == 0
+    {
+        return false;
+    }
+
+    let client_data = assertion.client_data_json.as_str();
+    if string_member(client_data, "type") != Some("webauthn.get")
+        || string_member(client_data, "challenge")
+            != Some(base64url::encode(&assertion.challenge).as_str())
+    {
+        return false;
+    }
+
+    let Ok(verifying_key) = VerifyingKey::from_sec1_bytes(&assertion.public_key) else {
+        return false;
+    };
+    let Ok(signature) = Signature::from_der(&assertion.signature) else {
+        return false;
+    };
+    let mut message = authenticator_data.clone();
+    message.extend_from_slice(&sha2::sha2(client_data.as_bytes()));
+    verifying_key.verify(&message, &signature).is_ok()
+}
+
+/// Verifies every assertion of the batch.
+pub fn verify_batch(assertions: &[WebAuthnAssertion]) -> bool {
+    assertions.iter().all(verify_assertion)
+}
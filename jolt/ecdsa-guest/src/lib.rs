@@ -1,6 +1,8 @@
 #![cfg_attr(feature = "guest", no_std)]
 #![no_main]
 
+extern crate alloc;
+use alloc::vec::Vec;
 use guests::ecdsa;
 
 #[jolt::provable(
@@ -8,6 +10,6 @@ use guests::ecdsa;
     memory_size = 10000000,
     max_input_size = 10000000
 )]
-pub fn ecdsa_verify(input: ecdsa::EcdsaVerifyInput) -> bool {
-    ecdsa::ecdsa_verify(input)
+pub fn ecdsa_verify(input: Vec<ecdsa::EcdsaVerifyInput>) -> bool {
+    ecdsa::ecdsa_verify_batch(input)
 }
@@ -0,0 +1,15 @@
+#![cfg_attr(feature = "guest", no_std)]
+#![no_main]
+
+extern crate alloc;
+use alloc::vec::Vec;
+use guests::ed25519;
+
+#[jolt::provable(
+    stack_size = 1000_000,
+    memory_size = 10000000,
+    max_input_size = 10000000
+)]
+pub fn ed25519_verify(input: Vec<ed25519::Ed25519VerifyInput>) -> bool {
+    ed25519::ed25519_verify_batch(input)
+}
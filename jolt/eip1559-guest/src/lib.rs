@@ -0,0 +1,13 @@
+#![cfg_attr(feature = "guest", no_std)]
+#![no_main]
+
+use guests::eip1559;
+
+#[jolt::provable(
+    stack_size = 1000_000,
+    memory_size = 10000000,
+    max_input_size = 10000000
+)]
+pub fn recover_sender(input: &[u8]) -> [u8; 20] {
+    eip1559::recover_sender(input).expect("invalid transaction")
+}
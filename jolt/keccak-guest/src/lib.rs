@@ -0,0 +1,9 @@
+#![cfg_attr(feature = "guest", no_std)]
+#![no_main]
+
+use guests::keccak;
+
+#[jolt::provable]
+fn keccak(input: &[u8]) -> [u8; 32] {
+    keccak::keccak(input)
+}
@@ -0,0 +1,13 @@
+#![cfg_attr(feature = "guest", no_std)]
+#![no_main]
+
+use guests::merkle;
+
+#[jolt::provable(
+    stack_size = 1000_000,
+    memory_size = 10000000,
+    max_input_size = 10000000
+)]
+pub fn merkle_verify(input: merkle::MerkleInput) -> bool {
+    merkle::verify_batch(&input)
+}
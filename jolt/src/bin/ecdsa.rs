@@ -1,44 +1,79 @@
 use jolt::Serializable;
 use std::time::Instant;
-use utils::{bench::benchmark, bench::Metrics, ecdsa_input, metadata::ECDSA_INPUTS};
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    ecdsa_input,
+    metadata::ECDSA_INPUTS,
+    profile::{phase, Phase},
+    stats::{InstructionStats, TOP_PCS},
+};
 
 const TARGET_DIR: &str = "./ecdsa-guest";
 
 fn main() {
-    let csv_file = format!(
-        "../.outputs/benchmark/ecdsa_jolt{}{}.csv",
-        if cfg!(feature = "icicle") { "-gpu" } else { "" },
-        ""
-    );
+    let gpu = if cfg!(feature = "icicle") { "-gpu" } else { "" };
 
-    benchmark(bench_ecdsa, &ECDSA_INPUTS, &csv_file);
+    benchmark(
+        bench_ecdsa,
+        &ECDSA_INPUTS,
+        Target::from_args(
+            "ecdsa",
+            &format!("jolt{}", gpu),
+            &format!("../.outputs/benchmark/ecdsa_jolt{}.csv", gpu),
+        ),
+    );
 }
 
 fn bench_ecdsa(size: usize) -> Metrics {
     let mut metrics = Metrics::new(size as usize);
-    let input = ecdsa_input();
+    let input = ecdsa_input(size);
 
-    let program = ecdsa_guest::compile_ecdsa_verify(TARGET_DIR);
-    let prover_preprocessing = ecdsa_guest::preprocess_prover_ecdsa_verify(&program);
+    let program = phase(Phase::Load, || {
+        ecdsa_guest::compile_ecdsa_verify(TARGET_DIR)
+    });
+    let prover_preprocessing = phase(Phase::Setup, || {
+        ecdsa_guest::preprocess_prover_ecdsa_verify(&program)
+    });
     let verifier_preprocessing = ecdsa_guest::preprocess_verifier_ecdsa_verify(&program);
 
+    metrics.pk_bytes = prover_preprocessing.size().unwrap();
+    metrics.vk_bytes = verifier_preprocessing.size().unwrap();
+    metrics.program_bytes = program.get_elf_contents().map_or(0, |elf| elf.len());
+
     let prover = ecdsa_guest::build_prover_ecdsa_verify(program, prover_preprocessing);
     let verifier = ecdsa_guest::build_verifier_ecdsa_verify(verifier_preprocessing);
 
     let start = Instant::now();
-    let program_summary = ecdsa_guest::analyze_ecdsa_verify(input.clone());
+    let program_summary = phase(Phase::Execute, || {
+        ecdsa_guest::analyze_ecdsa_verify(input.clone())
+    });
     metrics.exec_duration = start.elapsed();
     metrics.cycles = program_summary.processed_trace.len() as u64;
-    // save_summary_to_json(&program_summary, "../.outputs/traces/ecdsa_jolt.json")
-    //     .expect("Failed to save program summary");
+
+    InstructionStats::from_histograms(
+        program_summary
+            .raw_trace
+            .iter()
+            .map(|row| (format!("{:?}", row.instruction.opcode), 1)),
+        std::iter::empty::<(String, u64)>(),
+    )
+    .with_hot_pcs(
+        program_summary
+            .raw_trace
+            .iter()
+            .map(|row| row.instruction.address),
+        TOP_PCS,
+    )
+    .write("ecdsa", "jolt", size);
 
     let start = Instant::now();
-    let (output, proof) = prover(input.clone());
+    let (output, proof) = phase(Phase::Prove, || prover(input.clone()));
     metrics.proof_duration = start.elapsed();
     metrics.proof_bytes = proof.size().unwrap();
 
     let start = Instant::now();
-    let _verify_result = verifier(input.clone(), output, proof);
+    let _verify_result = phase(Phase::Verify, || verifier(input.clone(), output, proof));
     metrics.verify_duration = start.elapsed();
 
     metrics
@@ -0,0 +1,81 @@
+use jolt::Serializable;
+use std::time::Instant;
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    ed25519_input,
+    metadata::ED25519_INPUTS,
+    profile::{phase, Phase},
+    stats::{InstructionStats, TOP_PCS},
+};
+
+const TARGET_DIR: &str = "./ed25519-guest";
+
+fn main() {
+    let gpu = if cfg!(feature = "icicle") { "-gpu" } else { "" };
+
+    benchmark(
+        bench_ed25519,
+        &ED25519_INPUTS,
+        Target::from_args(
+            "ed25519",
+            &format!("jolt{}", gpu),
+            &format!("../.outputs/benchmark/ed25519_jolt{}.csv", gpu),
+        ),
+    );
+}
+
+fn bench_ed25519(size: usize) -> Metrics {
+    let mut metrics = Metrics::new(size as usize);
+    let input = ed25519_input(size);
+
+    let program = phase(Phase::Load, || {
+        ed25519_guest::compile_ed25519_verify(TARGET_DIR)
+    });
+    let prover_preprocessing = phase(Phase::Setup, || {
+        ed25519_guest::preprocess_prover_ed25519_verify(&program)
+    });
+    let verifier_preprocessing = ed25519_guest::preprocess_verifier_ed25519_verify(&program);
+
+    metrics.pk_bytes = prover_preprocessing.size().unwrap();
+    metrics.vk_bytes = verifier_preprocessing.size().unwrap();
+    metrics.program_bytes = program.get_elf_contents().map_or(0, |elf| elf.len());
+
+    let prover = ed25519_guest::build_prover_ed25519_verify(program, prover_preprocessing);
+    let verifier = ed25519_guest::build_verifier_ed25519_verify(verifier_preprocessing);
+
+    let start = Instant::now();
+    let program_summary = phase(Phase::Execute, || {
+        ed25519_guest::analyze_ed25519_verify(input.clone())
+    });
+    metrics.exec_duration = start.elapsed();
+    metrics.cycles = program_summary.processed_trace.len() as u64;
+
+    InstructionStats::from_histograms(
+        program_summary
+            .raw_trace
+            .iter()
+            .map(|row| (format!("{:?}", row.instruction.opcode), 1)),
+        std::iter::empty::<(String, u64)>(),
+    )
+    .with_hot_pcs(
+        program_summary
+            .raw_trace
+            .iter()
+            .map(|row| row.instruction.address),
+        TOP_PCS,
+    )
+    .write("ed25519", "jolt", size);
+
+    let start = Instant::now();
+    let (output, proof) = phase(Phase::Prove, || prover(input.clone()));
+    metrics.proof_duration = start.elapsed();
+    metrics.proof_bytes = proof.size().unwrap();
+    assert!(output, "invalid signatures");
+
+    let start = Instant::now();
+    let _verify_result = phase(Phase::Verify, || verifier(input.clone(), output, proof));
+    metrics.verify_duration = start.elapsed();
+
+    metrics
+}
@@ -0,0 +1,81 @@
+use jolt::Serializable;
+use std::time::Instant;
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    eip1559_input, eip1559_sender,
+    metadata::EIP1559_INPUTS,
+    profile::{phase, Phase},
+    stats::{InstructionStats, TOP_PCS},
+};
+
+const TARGET_DIR: &str = "./eip1559-guest";
+
+fn main() {
+    let gpu = if cfg!(feature = "icicle") { "-gpu" } else { "" };
+
+    benchmark(
+        benchmark_eip1559,
+        &EIP1559_INPUTS,
+        Target::from_args(
+            "eip1559",
+            &format!("jolt{}", gpu),
+            &format!("../.outputs/benchmark/eip1559_jolt{}.csv", gpu),
+        ),
+    );
+}
+
+fn benchmark_eip1559(calldata_bytes: usize) -> Metrics {
+    let mut metrics = Metrics::new(calldata_bytes as usize);
+
+    let program = phase(Phase::Load, || {
+        eip1559_guest::compile_recover_sender(TARGET_DIR)
+    });
+    let prover_preprocessing = phase(Phase::Setup, || {
+        eip1559_guest::preprocess_prover_recover_sender(&program)
+    });
+    let verifier_preprocessing = eip1559_guest::preprocess_verifier_recover_sender(&program);
+
+    metrics.pk_bytes = prover_preprocessing.size().unwrap();
+    metrics.vk_bytes = verifier_preprocessing.size().unwrap();
+    metrics.program_bytes = program.get_elf_contents().map_or(0, |elf| elf.len());
+
+    let prover = eip1559_guest::build_prover_recover_sender(program, prover_preprocessing);
+    let verifier = eip1559_guest::build_verifier_recover_sender(verifier_preprocessing);
+
+    let input = eip1559_input(calldata_bytes);
+    let start = Instant::now();
+    let program_summary = phase(Phase::Execute, || {
+        eip1559_guest::analyze_recover_sender(&input)
+    });
+    metrics.exec_duration = start.elapsed();
+    metrics.cycles = program_summary.processed_trace.len() as u64;
+
+    InstructionStats::from_histograms(
+        program_summary
+            .raw_trace
+            .iter()
+            .map(|row| (format!("{:?}", row.instruction.opcode), 1)),
+        std::iter::empty::<(String, u64)>(),
+    )
+    .with_hot_pcs(
+        program_summary
+            .raw_trace
+            .iter()
+            .map(|row| row.instruction.address),
+        TOP_PCS,
+    )
+    .write("eip1559", "jolt", calldata_bytes);
+
+    let start = Instant::now();
+    let (output, proof) = phase(Phase::Prove, || prover(&input));
+    metrics.proof_duration = start.elapsed();
+    metrics.proof_bytes = proof.size().unwrap();
+    assert_eq!(output, eip1559_sender(calldata_bytes));
+
+    let start = Instant::now();
+    let _verify_result = phase(Phase::Verify, || verifier(&input, output, proof));
+    metrics.verify_duration = start.elapsed();
+
+    metrics
+}
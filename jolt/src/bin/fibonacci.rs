@@ -1,46 +1,73 @@
 use jolt::Serializable;
 use std::time::Instant;
-use utils::{bench::benchmark, bench::Metrics, metadata::FIBONACCI_INPUTS, write_json};
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    metadata::FIBONACCI_INPUTS,
+    profile::{phase, Phase},
+    stats::{InstructionStats, TOP_PCS},
+};
 
 const TARGET_DIR: &str = "./fibonacci-guest";
 
 fn main() {
-    let csv_file = format!(
-        "../.outputs/benchmark/fib_jolt{}{}.csv",
-        if cfg!(feature = "icicle") { "-gpu" } else { "" },
-        ""
-    );
+    let gpu = if cfg!(feature = "icicle") { "-gpu" } else { "" };
 
-    benchmark(benchmark_fib, &FIBONACCI_INPUTS, &csv_file);
+    benchmark(
+        benchmark_fib,
+        &FIBONACCI_INPUTS,
+        Target::from_args(
+            "fib",
+            &format!("jolt{}", gpu),
+            &format!("../.outputs/benchmark/fib_jolt{}.csv", gpu),
+        ),
+    );
 }
 
 fn benchmark_fib(n: u32) -> Metrics {
     let mut metrics = Metrics::new(n as usize);
 
-    let program = fibonacci_guest::compile_fib(TARGET_DIR);
-    let prover_preprocessing = fibonacci_guest::preprocess_prover_fib(&program);
+    let program = phase(Phase::Load, || fibonacci_guest::compile_fib(TARGET_DIR));
+    let prover_preprocessing = phase(Phase::Setup, || {
+        fibonacci_guest::preprocess_prover_fib(&program)
+    });
     let verifier_preprocessing = fibonacci_guest::preprocess_verifier_fib(&program);
 
+    metrics.pk_bytes = prover_preprocessing.size().unwrap();
+    metrics.vk_bytes = verifier_preprocessing.size().unwrap();
+    metrics.program_bytes = program.get_elf_contents().map_or(0, |elf| elf.len());
+
     let prover = fibonacci_guest::build_prover_fib(program, prover_preprocessing);
     let verifier = fibonacci_guest::build_verifier_fib(verifier_preprocessing);
 
     let start = Instant::now();
-    let program_summary = fibonacci_guest::analyze_fib(n);
+    let program_summary = phase(Phase::Execute, || fibonacci_guest::analyze_fib(n));
     metrics.exec_duration = start.elapsed();
     metrics.cycles = program_summary.processed_trace.len() as u64;
 
-    write_json(
-        &program_summary,
-        &format!("../.outputs/traces/fib_jolt_{}.json", n),
-    );
+    InstructionStats::from_histograms(
+        program_summary
+            .raw_trace
+            .iter()
+            .map(|row| (format!("{:?}", row.instruction.opcode), 1)),
+        std::iter::empty::<(String, u64)>(),
+    )
+    .with_hot_pcs(
+        program_summary
+            .raw_trace
+            .iter()
+            .map(|row| row.instruction.address),
+        TOP_PCS,
+    )
+    .write("fib", "jolt", n as usize);
 
     let start = Instant::now();
-    let (output, proof) = prover(n);
+    let (output, proof) = phase(Phase::Prove, || prover(n));
     metrics.proof_duration = start.elapsed();
     metrics.proof_bytes = proof.size().unwrap();
 
     let start = Instant::now();
-    let _verify_result = verifier(n, output, proof);
+    let _verify_result = phase(Phase::Verify, || verifier(n, output, proof));
     metrics.verify_duration = start.elapsed();
 
     metrics
@@ -0,0 +1,77 @@
+use jolt::Serializable;
+use std::time::Instant;
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    keccak_digest, keccak_input,
+    metadata::KECCAK_INPUTS,
+    profile::{phase, Phase},
+    stats::{InstructionStats, TOP_PCS},
+};
+
+const TARGET_DIR: &str = "./keccak-guest";
+
+fn main() {
+    let gpu = if cfg!(feature = "icicle") { "-gpu" } else { "" };
+
+    benchmark(
+        benchmark_keccak,
+        &KECCAK_INPUTS,
+        Target::from_args(
+            "keccak",
+            &format!("jolt{}", gpu),
+            &format!("../.outputs/benchmark/keccak_jolt{}.csv", gpu),
+        ),
+    );
+}
+
+fn benchmark_keccak(num_bytes: usize) -> Metrics {
+    let mut metrics = Metrics::new(num_bytes as usize);
+
+    let program = phase(Phase::Load, || keccak_guest::compile_keccak(TARGET_DIR));
+    let prover_preprocessing = phase(Phase::Setup, || {
+        keccak_guest::preprocess_prover_keccak(&program)
+    });
+    let verifier_preprocessing = keccak_guest::preprocess_verifier_keccak(&program);
+
+    metrics.pk_bytes = prover_preprocessing.size().unwrap();
+    metrics.vk_bytes = verifier_preprocessing.size().unwrap();
+    metrics.program_bytes = program.get_elf_contents().map_or(0, |elf| elf.len());
+
+    let prover = keccak_guest::build_prover_keccak(program, prover_preprocessing);
+    let verifier = keccak_guest::build_verifier_keccak(verifier_preprocessing);
+
+    let input = keccak_input(num_bytes);
+    let start = Instant::now();
+    let program_summary = phase(Phase::Execute, || keccak_guest::analyze_keccak(&input));
+    metrics.exec_duration = start.elapsed();
+    metrics.cycles = program_summary.processed_trace.len() as u64;
+
+    InstructionStats::from_histograms(
+        program_summary
+            .raw_trace
+            .iter()
+            .map(|row| (format!("{:?}", row.instruction.opcode), 1)),
+        std::iter::empty::<(String, u64)>(),
+    )
+    .with_hot_pcs(
+        program_summary
+            .raw_trace
+            .iter()
+            .map(|row| row.instruction.address),
+        TOP_PCS,
+    )
+    .write("keccak", "jolt", num_bytes);
+
+    let start = Instant::now();
+    let (output, proof) = phase(Phase::Prove, || prover(&input));
+    metrics.proof_duration = start.elapsed();
+    metrics.proof_bytes = proof.size().unwrap();
+    assert_eq!(output, keccak_digest(&input));
+
+    let start = Instant::now();
+    let _verify_result = phase(Phase::Verify, || verifier(&input, output, proof));
+    metrics.verify_duration = start.elapsed();
+
+    metrics
+}
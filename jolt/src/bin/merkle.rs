@@ -0,0 +1,81 @@
+use jolt::Serializable;
+use std::time::Instant;
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    merkle_input, merkle_params,
+    params::Params,
+    profile::{phase, Phase},
+    stats::{InstructionStats, TOP_PCS},
+};
+
+const TARGET_DIR: &str = "./merkle-guest";
+
+fn main() {
+    let gpu = if cfg!(feature = "icicle") { "-gpu" } else { "" };
+
+    benchmark(
+        bench_merkle,
+        merkle_params(),
+        Target::from_args(
+            "merkle",
+            &format!("jolt{}", gpu),
+            &format!("../.outputs/benchmark/merkle_jolt{}.csv", gpu),
+        ),
+    );
+}
+
+fn bench_merkle(params: Params) -> Metrics {
+    let mut metrics = Metrics::new(params.clone());
+    let input = merkle_input(&params);
+
+    let program = phase(Phase::Load, || {
+        merkle_guest::compile_merkle_verify(TARGET_DIR)
+    });
+    let prover_preprocessing = phase(Phase::Setup, || {
+        merkle_guest::preprocess_prover_merkle_verify(&program)
+    });
+    let verifier_preprocessing = merkle_guest::preprocess_verifier_merkle_verify(&program);
+
+    metrics.pk_bytes = prover_preprocessing.size().unwrap();
+    metrics.vk_bytes = verifier_preprocessing.size().unwrap();
+    metrics.program_bytes = program.get_elf_contents().map_or(0, |elf| elf.len());
+
+    let prover = merkle_guest::build_prover_merkle_verify(program, prover_preprocessing);
+    let verifier = merkle_guest::build_verifier_merkle_verify(verifier_preprocessing);
+
+    let start = Instant::now();
+    let program_summary = phase(Phase::Execute, || {
+        merkle_guest::analyze_merkle_verify(input.clone())
+    });
+    metrics.exec_duration = start.elapsed();
+    metrics.cycles = program_summary.processed_trace.len() as u64;
+
+    InstructionStats::from_histograms(
+        program_summary
+            .raw_trace
+            .iter()
+            .map(|row| (format!("{:?}", row.instruction.opcode), 1)),
+        std::iter::empty::<(String, u64)>(),
+    )
+    .with_hot_pcs(
+        program_summary
+            .raw_trace
+            .iter()
+            .map(|row| row.instruction.address),
+        TOP_PCS,
+    )
+    .write("merkle", "jolt", &params);
+
+    let start = Instant::now();
+    let (output, proof) = phase(Phase::Prove, || prover(input.clone()));
+    metrics.proof_duration = start.elapsed();
+    metrics.proof_bytes = proof.size().unwrap();
+    assert!(output, "invalid inclusion proofs");
+
+    let start = Instant::now();
+    let _verify_result = phase(Phase::Verify, || verifier(input.clone(), output, proof));
+    metrics.verify_duration = start.elapsed();
+
+    metrics
+}
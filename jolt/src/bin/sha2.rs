@@ -1,42 +1,75 @@
 use jolt::Serializable;
 use std::time::Instant;
-use utils::{bench::benchmark, bench::Metrics, metadata::SHA2_INPUTS, sha2_input};
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    metadata::SHA2_INPUTS,
+    profile::{phase, Phase},
+    sha2_input,
+    stats::{InstructionStats, TOP_PCS},
+};
 
 const TARGET_DIR: &str = "./sha2-guest";
 
 fn main() {
-    let csv_file = format!(
-        "../.outputs/benchmark/sha2_jolt{}{}.csv",
-        if cfg!(feature = "icicle") { "-gpu" } else { "" },
-        ""
-    );
+    let gpu = if cfg!(feature = "icicle") { "-gpu" } else { "" };
 
-    benchmark(benchmark_sha2, &SHA2_INPUTS, &csv_file);
+    benchmark(
+        benchmark_sha2,
+        &SHA2_INPUTS,
+        Target::from_args(
+            "sha2",
+            &format!("jolt{}", gpu),
+            &format!("../.outputs/benchmark/sha2_jolt{}.csv", gpu),
+        ),
+    );
 }
 
 fn benchmark_sha2(num_bytes: usize) -> Metrics {
     let mut metrics = Metrics::new(num_bytes as usize);
 
-    let program = sha2_guest::compile_sha2(TARGET_DIR);
-    let prover_preprocessing = sha2_guest::preprocess_prover_sha2(&program);
+    let program = phase(Phase::Load, || sha2_guest::compile_sha2(TARGET_DIR));
+    let prover_preprocessing = phase(Phase::Setup, || {
+        sha2_guest::preprocess_prover_sha2(&program)
+    });
     let verifier_preprocessing = sha2_guest::preprocess_verifier_sha2(&program);
 
+    metrics.pk_bytes = prover_preprocessing.size().unwrap();
+    metrics.vk_bytes = verifier_preprocessing.size().unwrap();
+    metrics.program_bytes = program.get_elf_contents().map_or(0, |elf| elf.len());
+
     let prover = sha2_guest::build_prover_sha2(program, prover_preprocessing);
     let verifier = sha2_guest::build_verifier_sha2(verifier_preprocessing);
 
     let input = sha2_input(num_bytes);
     let start = Instant::now();
-    let program_summary = sha2_guest::analyze_sha2(&input);
+    let program_summary = phase(Phase::Execute, || sha2_guest::analyze_sha2(&input));
     metrics.exec_duration = start.elapsed();
     metrics.cycles = program_summary.processed_trace.len() as u64;
 
+    InstructionStats::from_histograms(
+        program_summary
+            .raw_trace
+            .iter()
+            .map(|row| (format!("{:?}", row.instruction.opcode), 1)),
+        std::iter::empty::<(String, u64)>(),
+    )
+    .with_hot_pcs(
+        program_summary
+            .raw_trace
+            .iter()
+            .map(|row| row.instruction.address),
+        TOP_PCS,
+    )
+    .write("sha2", "jolt", num_bytes);
+
     let start = Instant::now();
-    let (output, proof) = prover(&input);
+    let (output, proof) = phase(Phase::Prove, || prover(&input));
     metrics.proof_duration = start.elapsed();
     metrics.proof_bytes = proof.size().unwrap();
 
     let start = Instant::now();
-    let _verify_result = verifier(&input, output, proof);
+    let _verify_result = phase(Phase::Verify, || verifier(&input, output, proof));
     metrics.verify_duration = start.elapsed();
 
     metrics
@@ -1,43 +1,79 @@
 use jolt::Serializable;
 use std::time::Instant;
-use utils::{bench::benchmark, bench::Metrics, metadata::ETHTRANSFER_INPUTS};
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    metadata::ETHTRANSFER_INPUTS,
+    profile::{phase, Phase},
+    stats::{InstructionStats, TOP_PCS},
+};
 
 const TARGET_DIR: &str = "./transfer-eth-guest";
 
 fn main() {
-    let csv_file = format!(
-        "../.outputs/benchmark/ethblock_jolt{}{}.csv",
-        if cfg!(feature = "icicle") { "-gpu" } else { "" },
-        ""
-    );
+    let gpu = if cfg!(feature = "icicle") { "-gpu" } else { "" };
 
-    benchmark(benchmark_transfer_eth, &ETHTRANSFER_INPUTS, &csv_file);
+    benchmark(
+        benchmark_transfer_eth,
+        &ETHTRANSFER_INPUTS,
+        Target::from_args(
+            "ethblock",
+            &format!("jolt{}", gpu),
+            &format!("../.outputs/benchmark/ethblock_jolt{}.csv", gpu),
+        ),
+    );
 }
 
 fn benchmark_transfer_eth(n: usize) -> Metrics {
     let mut metrics = Metrics::new(n as usize);
 
-    let program = transfer_eth_guest::compile_transfer_eth_n_times(TARGET_DIR);
-    let prover_preprocessing = transfer_eth_guest::preprocess_prover_transfer_eth_n_times(&program);
+    let program = phase(Phase::Load, || {
+        transfer_eth_guest::compile_transfer_eth_n_times(TARGET_DIR)
+    });
+    let prover_preprocessing = phase(Phase::Setup, || {
+        transfer_eth_guest::preprocess_prover_transfer_eth_n_times(&program)
+    });
     let verifier_preprocessing =
         transfer_eth_guest::preprocess_verifier_transfer_eth_n_times(&program);
 
+    metrics.pk_bytes = prover_preprocessing.size().unwrap();
+    metrics.vk_bytes = verifier_preprocessing.size().unwrap();
+    metrics.program_bytes = program.get_elf_contents().map_or(0, |elf| elf.len());
+
     let prover =
         transfer_eth_guest::build_prover_transfer_eth_n_times(program, prover_preprocessing);
     let verifier = transfer_eth_guest::build_verifier_transfer_eth_n_times(verifier_preprocessing);
 
     let start = Instant::now();
-    let program_summary = transfer_eth_guest::analyze_transfer_eth_n_times(n);
+    let program_summary = phase(Phase::Execute, || {
+        transfer_eth_guest::analyze_transfer_eth_n_times(n)
+    });
     metrics.exec_duration = start.elapsed();
     metrics.cycles = program_summary.processed_trace.len() as u64;
 
+    InstructionStats::from_histograms(
+        program_summary
+            .raw_trace
+            .iter()
+            .map(|row| (format!("{:?}", row.instruction.opcode), 1)),
+        std::iter::empty::<(String, u64)>(),
+    )
+    .with_hot_pcs(
+        program_summary
+            .raw_trace
+            .iter()
+            .map(|row| row.instruction.address),
+        TOP_PCS,
+    )
+    .write("ethtransfer", "jolt", n);
+
     let start = Instant::now();
-    let (output, proof) = prover(n);
+    let (output, proof) = phase(Phase::Prove, || prover(n));
     metrics.proof_duration = start.elapsed();
     metrics.proof_bytes = proof.size().unwrap();
 
     let start = Instant::now();
-    let _verify_result = verifier(n, output, proof);
+    let _verify_result = phase(Phase::Verify, || verifier(n, output, proof));
     metrics.verify_duration = start.elapsed();
 
     metrics
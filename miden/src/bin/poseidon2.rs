@@ -0,0 +1,49 @@
+use miden::bench_miden;
+use miden_processor::crypto::hash::Rpo256;
+use miden_processor::math::Felt;
+use miden_vm::StackInputs;
+use std::fs;
+use utils::{
+    bench::{benchmark, Metrics, Target},
+    metadata::POSEIDON2_INPUTS,
+    params::{Params, SIZE},
+    poseidon2_params,
+};
+
+/// Rust reference: the top 12 elements of the stack after `n` permutations. `hperm` keeps the
+/// state in reverse order on the stack.
+fn hash_chain(n: u32) -> Vec<Felt> {
+    let mut state = [Felt::new(0); 12];
+    for _ in 0..n {
+        Rpo256::apply_permutation(&mut state);
+    }
+    state.into_iter().rev().collect()
+}
+
+fn bench_miden_poseidon2(params: Params) -> Metrics {
+    let n = params.u64(SIZE) as u32;
+    let program_string = fs::read_to_string("src/poseidon2_repeat.masm")
+        .expect("Failed to read poseidon2_repeat.masm")
+        .replace("Z", &n.to_string());
+
+    bench_miden(
+        Metrics::new(params),
+        program_string,
+        StackInputs::default(),
+        hash_chain(n),
+    )
+}
+
+fn main() {
+    dotenv::dotenv().ok();
+    benchmark(
+        bench_miden_poseidon2,
+        // Miden has no Poseidon2, `hperm` is the Rescue Prime Optimized permutation.
+        poseidon2_params(&POSEIDON2_INPUTS, "rpo", "goldilocks"),
+        Target::from_args(
+            "poseidon2",
+            "miden",
+            "../.outputs/benchmark/poseidon2_miden.csv",
+        ),
+    );
+}
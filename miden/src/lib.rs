@@ -0,0 +1,79 @@
+use miden_processor::execute;
+use miden_processor::math::Felt;
+use miden_processor::ExecutionOptions;
+use miden_vm::{
+    assembly::DefaultSourceManager, prove, verify, Assembler, DefaultHost, ProgramInfo,
+    ProvingOptions,
+};
+use miden_vm::{AdviceInputs, StackInputs, StackOutputs};
+use std::sync::Arc;
+use std::time::Instant;
+use utils::bench::Metrics;
+
+/// Executes, proves and verifies a MASM program, checking that it leaves `expected` on top of the
+/// stack.
+pub fn bench_miden(
+    mut metrics: Metrics,
+    program_string: String,
+    stack_inputs: StackInputs,
+    expected: Vec<Felt>,
+) -> Metrics {
+    // Compile the program
+    let assembler = Assembler::default();
+    let program = assembler
+        .assemble_program(program_string)
+        .expect("Failed to assemble program");
+
+    // Prepare inputs
+    let advice_inputs = AdviceInputs::default();
+    let source_manager = Arc::new(DefaultSourceManager::default());
+
+    // Execute
+    let execution_start = Instant::now();
+    let trace = execute(
+        &program,
+        stack_inputs.clone(),
+        advice_inputs.clone(),
+        &mut DefaultHost::default(),
+        ExecutionOptions::default(),
+        source_manager,
+    )
+    .expect("Failed to execute Miden program");
+    metrics.exec_duration = execution_start.elapsed();
+    metrics.cycles = trace.get_trace_len() as u64;
+
+    // Prove and execute (not possible to isolate the proof generation due to ExecutionProver being private)
+    // An approximation of the proof duration is done by subtracting the execution duration from the total duration
+    let execution_proving_start = Instant::now();
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let (outputs, proof) = prove(
+        &program,
+        stack_inputs.clone(),
+        advice_inputs.clone(),
+        &mut DefaultHost::default(),
+        ProvingOptions::default(),
+        source_manager,
+    )
+    .expect("Failed to prove Miden program execution");
+    let execution_proving_end = execution_proving_start.elapsed();
+    metrics.proof_duration = execution_proving_end - metrics.exec_duration;
+    metrics.proof_bytes = proof.to_bytes().len();
+
+    let output: Vec<Felt> = (0..expected.len())
+        .map(|i| outputs.get_stack_item(i).unwrap())
+        .collect();
+    assert_eq!(output, expected);
+
+    // Verify
+    let verification_start = Instant::now();
+    verify(
+        ProgramInfo::new(program.hash(), program.kernel().clone()),
+        stack_inputs.clone(),
+        StackOutputs::new(expected).unwrap(),
+        proof,
+    )
+    .expect("Failed to verify Miden proof");
+    metrics.verify_duration = verification_start.elapsed();
+
+    metrics
+}
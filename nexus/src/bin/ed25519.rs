@@ -0,0 +1,80 @@
+use guests::ed25519::Ed25519VerifyInput;
+use nexus_sdk::{
+    ByGuestCompilation, Local, Prover, Verifiable, Viewable,
+    compile::{Compile, Compiler, cargo::CargoPackager},
+    stwo::seq::Stwo,
+};
+use std::time::Instant;
+use utils::{
+    bench::Metrics,
+    bench::{Target, benchmark},
+    ed25519_input,
+    metadata::ED25519_INPUTS,
+    profile::{Phase, phase},
+    size,
+};
+
+const PACKAGE: &str = "ed25519-guest";
+
+fn main() {
+    benchmark(
+        benchmark_ed25519,
+        &ED25519_INPUTS,
+        Target::from_args(
+            "ed25519",
+            "nexus",
+            "../.outputs/benchmark/ed25519_nexus.csv",
+        ),
+    );
+}
+
+fn benchmark_ed25519(n: usize) -> Metrics {
+    let mut metrics: Metrics = Metrics::new(n);
+    let input = ed25519_input(n);
+
+    let mut prover_compiler = Compiler::<CargoPackager>::new(PACKAGE);
+    let prover: Stwo<Local> = phase(Phase::Load, || {
+        Stwo::compile(&mut prover_compiler).expect("failed to compile guest program")
+    });
+
+    let elf = prover.elf.clone(); // save elf for use with verification
+    metrics.program_bytes = size(&elf);
+
+    let start = Instant::now();
+    let _ = phase(Phase::Execute, || {
+        prover
+            .run_with_input::<Vec<Ed25519VerifyInput>, ()>(&input, &())
+            .expect("failed to run program")
+    });
+    metrics.exec_duration = start.elapsed();
+
+    let start = Instant::now();
+    let (view, proof) = phase(Phase::Prove, || {
+        prover
+            .prove_with_input::<Vec<Ed25519VerifyInput>, ()>(&input, &())
+            .expect("failed to prove program")
+    });
+    metrics.proof_duration = start.elapsed();
+    metrics.proof_bytes = size(&proof);
+
+    let output = view
+        .public_output::<bool>()
+        .expect("failed to retrieve public output");
+    assert!(output, "invalid signatures");
+
+    let start = Instant::now();
+    phase(Phase::Verify, || {
+        proof
+            .verify_expected(
+                &(), // no public input
+                nexus_sdk::KnownExitCodes::ExitSuccess as u32,
+                &output, // no public output
+                &elf,    // expected elf (program binary)
+                &[],     // no associated data,
+            )
+            .expect("failed to verify proof")
+    });
+    metrics.verify_duration = start.elapsed();
+
+    metrics
+}
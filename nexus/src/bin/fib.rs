@@ -4,7 +4,13 @@ use nexus_sdk::{
     stwo::seq::Stwo,
 };
 use std::time::Instant;
-use utils::{bench::Metrics, bench::benchmark, metadata::FIBONACCI_INPUTS, size};
+use utils::{
+    bench::Metrics,
+    bench::{Target, benchmark},
+    metadata::FIBONACCI_INPUTS,
+    profile::{Phase, phase},
+    size,
+};
 
 const PACKAGE: &str = "fibonacci-guest";
 
@@ -12,7 +18,7 @@ fn main() {
     benchmark(
         benchmark_fib,
         &FIBONACCI_INPUTS,
-        "../.outputs/benchmark/fib_nexus.csv",
+        Target::from_args("fib", "nexus", "../.outputs/benchmark/fib_nexus.csv"),
     );
 }
 
@@ -20,21 +26,27 @@ fn benchmark_fib(n: u32) -> Metrics {
     let mut metrics: Metrics = Metrics::new(n as usize);
 
     let mut prover_compiler = Compiler::<CargoPackager>::new(PACKAGE);
-    let prover: Stwo<Local> =
-        Stwo::compile(&mut prover_compiler).expect("failed to compile guest program");
+    let prover: Stwo<Local> = phase(Phase::Load, || {
+        Stwo::compile(&mut prover_compiler).expect("failed to compile guest program")
+    });
 
     let elf = prover.elf.clone(); // save elf for use with verification
+    metrics.program_bytes = size(&elf);
 
     let start = Instant::now();
-    let _ = prover
-        .run_with_input::<u32, ()>(&n, &())
-        .expect("failed to run program");
+    let _ = phase(Phase::Execute, || {
+        prover
+            .run_with_input::<u32, ()>(&n, &())
+            .expect("failed to run program")
+    });
     metrics.exec_duration = start.elapsed();
 
     let start = Instant::now();
-    let (view, proof) = prover
-        .prove_with_input::<u32, ()>(&n, &())
-        .expect("failed to prove program");
+    let (view, proof) = phase(Phase::Prove, || {
+        prover
+            .prove_with_input::<u32, ()>(&n, &())
+            .expect("failed to prove program")
+    });
     metrics.proof_duration = start.elapsed();
     metrics.proof_bytes = size(&proof);
 
@@ -43,15 +55,17 @@ fn benchmark_fib(n: u32) -> Metrics {
         .expect("failed to retrieve public output");
 
     let start = Instant::now();
-    proof
-        .verify_expected(
-            &(), // no public input
-            nexus_sdk::KnownExitCodes::ExitSuccess as u32,
-            &output, // no public output
-            &elf,    // expected elf (program binary)
-            &[],     // no associated data,
-        )
-        .expect("failed to verify proof");
+    phase(Phase::Verify, || {
+        proof
+            .verify_expected(
+                &(), // no public input
+                nexus_sdk::KnownExitCodes::ExitSuccess as u32,
+                &output, // no public output
+                &elf,    // expected elf (program binary)
+                &[],     // no associated data,
+            )
+            .expect("failed to verify proof")
+    });
     metrics.verify_duration = start.elapsed();
 
     metrics
@@ -0,0 +1,75 @@
+use nexus_sdk::{
+    ByGuestCompilation, Local, Prover, Verifiable, Viewable,
+    compile::{Compile, Compiler, cargo::CargoPackager},
+    stwo::seq::Stwo,
+};
+use std::time::Instant;
+use utils::{
+    bench::Metrics,
+    bench::{Target, benchmark},
+    keccak_digest, keccak_input,
+    metadata::KECCAK_INPUTS,
+    profile::{Phase, phase},
+    size,
+};
+
+const PACKAGE: &str = "keccak-guest";
+
+fn main() {
+    benchmark(
+        benchmark_keccak,
+        &KECCAK_INPUTS,
+        Target::from_args("keccak", "nexus", "../.outputs/benchmark/keccak_nexus.csv"),
+    );
+}
+
+fn benchmark_keccak(num_bytes: usize) -> Metrics {
+    let mut metrics: Metrics = Metrics::new(num_bytes as usize);
+    let input = keccak_input(num_bytes);
+
+    let mut prover_compiler = Compiler::<CargoPackager>::new(PACKAGE);
+    let prover: Stwo<Local> = phase(Phase::Load, || {
+        Stwo::compile(&mut prover_compiler).expect("failed to compile guest program")
+    });
+
+    let elf = prover.elf.clone(); // save elf for use with verification
+    metrics.program_bytes = size(&elf);
+
+    let start = Instant::now();
+    let _ = phase(Phase::Execute, || {
+        prover
+            .run_with_input::<Vec<u8>, ()>(&input, &())
+            .expect("failed to run program")
+    });
+    metrics.exec_duration = start.elapsed();
+
+    let start = Instant::now();
+    let (view, proof) = phase(Phase::Prove, || {
+        prover
+            .prove_with_input::<Vec<u8>, ()>(&input, &())
+            .expect("failed to prove program")
+    });
+    metrics.proof_duration = start.elapsed();
+    metrics.proof_bytes = size(&proof);
+
+    let output = view
+        .public_output::<[u8; 32]>()
+        .expect("failed to retrieve public output");
+    assert_eq!(output, keccak_digest(&input));
+
+    let start = Instant::now();
+    phase(Phase::Verify, || {
+        proof
+            .verify_expected(
+                &(), // no public input
+                nexus_sdk::KnownExitCodes::ExitSuccess as u32,
+                &output, // no public output
+                &elf,    // expected elf (program binary)
+                &[],     // no associated data,
+            )
+            .expect("failed to verify proof")
+    });
+    metrics.verify_duration = start.elapsed();
+
+    metrics
+}
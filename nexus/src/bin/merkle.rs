@@ -0,0 +1,76 @@
+use guests::merkle::MerkleInput;
+use nexus_sdk::{
+    ByGuestCompilation, Local, Prover, Verifiable, Viewable,
+    compile::{Compile, Compiler, cargo::CargoPackager},
+    stwo::seq::Stwo,
+};
+use std::time::Instant;
+use utils::{
+    bench::Metrics,
+    bench::{Target, benchmark},
+    merkle_input, merkle_params,
+    params::Params,
+    profile::{Phase, phase},
+    size,
+};
+
+const PACKAGE: &str = "merkle-guest";
+
+fn main() {
+    benchmark(
+        benchmark_merkle,
+        merkle_params(),
+        Target::from_args("merkle", "nexus", "../.outputs/benchmark/merkle_nexus.csv"),
+    );
+}
+
+fn benchmark_merkle(params: Params) -> Metrics {
+    let mut metrics: Metrics = Metrics::new(params.clone());
+    let input = merkle_input(&params);
+
+    let mut prover_compiler = Compiler::<CargoPackager>::new(PACKAGE);
+    let prover: Stwo<Local> = phase(Phase::Load, || {
+        Stwo::compile(&mut prover_compiler).expect("failed to compile guest program")
+    });
+
+    let elf = prover.elf.clone(); // save elf for use with verification
+    metrics.program_bytes = size(&elf);
+
+    let start = Instant::now();
+    let _ = phase(Phase::Execute, || {
+        prover
+            .run_with_input::<MerkleInput, ()>(&input, &())
+            .expect("failed to run program")
+    });
+    metrics.exec_duration = start.elapsed();
+
+    let start = Instant::now();
+    let (view, proof) = phase(Phase::Prove, || {
+        prover
+            .prove_with_input::<MerkleInput, ()>(&input, &())
+            .expect("failed to prove program")
+    });
+    metrics.proof_duration = start.elapsed();
+    metrics.proof_bytes = size(&proof);
+
+    let output = view
+        .public_output::<bool>()
+        .expect("failed to retrieve public output");
+    assert!(output, "invalid inclusion proofs");
+
+    let start = Instant::now();
+    phase(Phase::Verify, || {
+        proof
+            .verify_expected(
+                &(), // no public input
+                nexus_sdk::KnownExitCodes::ExitSuccess as u32,
+                &output, // no public output
+                &elf,    // expected elf (program binary)
+                &[],     // no associated data,
+            )
+            .expect("failed to verify proof")
+    });
+    metrics.verify_duration = start.elapsed();
+
+    metrics
+}
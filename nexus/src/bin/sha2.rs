@@ -4,7 +4,13 @@ use nexus_sdk::{
     stwo::seq::Stwo,
 };
 use std::time::Instant;
-use utils::{bench::Metrics, bench::benchmark, metadata::SHA2_INPUTS, sha2_input, size};
+use utils::{
+    bench::Metrics,
+    bench::{Target, benchmark},
+    metadata::SHA2_INPUTS,
+    profile::{Phase, phase},
+    sha2_input, size,
+};
 
 const PACKAGE: &str = "sha2-guest";
 
@@ -12,7 +18,7 @@ fn main() {
     benchmark(
         benchmark_sha2,
         &SHA2_INPUTS,
-        "../.outputs/benchmark/sha2_nexus.csv",
+        Target::from_args("sha2", "nexus", "../.outputs/benchmark/sha2_nexus.csv"),
     );
 }
 
@@ -21,21 +27,27 @@ fn benchmark_sha2(num_bytes: usize) -> Metrics {
     let input = sha2_input(num_bytes);
 
     let mut prover_compiler = Compiler::<CargoPackager>::new(PACKAGE);
-    let prover: Stwo<Local> =
-        Stwo::compile(&mut prover_compiler).expect("failed to compile guest program");
+    let prover: Stwo<Local> = phase(Phase::Load, || {
+        Stwo::compile(&mut prover_compiler).expect("failed to compile guest program")
+    });
 
     let elf = prover.elf.clone(); // save elf for use with verification
+    metrics.program_bytes = size(&elf);
 
     let start = Instant::now();
-    let _ = prover
-        .run_with_input::<Vec<u8>, ()>(&input, &())
-        .expect("failed to run program");
+    let _ = phase(Phase::Execute, || {
+        prover
+            .run_with_input::<Vec<u8>, ()>(&input, &())
+            .expect("failed to run program")
+    });
     metrics.exec_duration = start.elapsed();
 
     let start = Instant::now();
-    let (view, proof) = prover
-        .prove_with_input::<Vec<u8>, ()>(&input, &())
-        .expect("failed to prove program");
+    let (view, proof) = phase(Phase::Prove, || {
+        prover
+            .prove_with_input::<Vec<u8>, ()>(&input, &())
+            .expect("failed to prove program")
+    });
     metrics.proof_duration = start.elapsed();
     metrics.proof_bytes = size(&proof);
 
@@ -44,15 +56,17 @@ fn benchmark_sha2(num_bytes: usize) -> Metrics {
         .expect("failed to retrieve public output");
 
     let start = Instant::now();
-    proof
-        .verify_expected(
-            &(), // no public input
-            nexus_sdk::KnownExitCodes::ExitSuccess as u32,
-            &output, // no public output
-            &elf,    // expected elf (program binary)
-            &[],     // no associated data,
-        )
-        .expect("failed to verify proof");
+    phase(Phase::Verify, || {
+        proof
+            .verify_expected(
+                &(), // no public input
+                nexus_sdk::KnownExitCodes::ExitSuccess as u32,
+                &output, // no public output
+                &elf,    // expected elf (program binary)
+                &[],     // no associated data,
+            )
+            .expect("failed to verify proof")
+    });
     metrics.verify_duration = start.elapsed();
 
     metrics
@@ -0,0 +1,10 @@
+#![cfg_attr(target_arch = "riscv32", no_std, no_main)]
+
+extern crate alloc;
+use alloc::vec::Vec;
+use guests::ed25519;
+
+#[nexus_rt::main]
+fn main(input: Vec<ed25519::Ed25519VerifyInput>) -> bool {
+    ed25519::ed25519_verify_batch(input)
+}
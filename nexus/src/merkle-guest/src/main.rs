@@ -0,0 +1,8 @@
+#![cfg_attr(target_arch = "riscv32", no_std, no_main)]
+
+use guests::merkle;
+
+#[nexus_rt::main]
+fn main(input: merkle::MerkleInput) -> bool {
+    merkle::verify_batch(&input)
+}
@@ -0,0 +1,50 @@
+use acvm::{acir::AcirField, FieldElement};
+use bn254_blackbox_solver::poseidon2_permutation;
+use noir_provekit::{bench_noir, NoirProverError};
+use noirc_abi::input_parser::InputValue;
+use utils::{
+    bench::{benchmark, Metrics, Target},
+    metadata::POSEIDON2_INPUTS,
+    params::{Params, SIZE},
+    poseidon2_params,
+};
+
+/// Rust reference: the first element of the zero state after `n` Poseidon2 permutations of width
+/// 4, as `hash_chain` of `noir_poseidon2`.
+fn hash_chain(n: u32) -> FieldElement {
+    let mut state = vec![FieldElement::zero(); 4];
+    for _ in 0..n {
+        state = poseidon2_permutation(&state, 4).expect("Poseidon2 permutation failed");
+    }
+    state[0]
+}
+
+fn bench_noir_poseidon2(params: &Params) -> Result<Metrics, NoirProverError> {
+    let n = params.u64(SIZE) as u32;
+    let (metrics, return_value) = bench_noir(
+        Metrics::new(params.clone()),
+        &format!("runner/test_data/target/noir_poseidon2_{}.json", n),
+        n,
+    )?;
+    assert_eq!(return_value, Some(InputValue::Field(hash_chain(n))));
+    Ok(metrics)
+}
+
+fn main() {
+    dotenv::dotenv().ok();
+
+    let bench_fn = |params: Params| -> Metrics {
+        bench_noir_poseidon2(&params)
+            .unwrap_or_else(|e| panic!("Benchmark failed for {}: {}", params, e))
+    };
+
+    benchmark(
+        bench_fn,
+        poseidon2_params(&POSEIDON2_INPUTS, "poseidon2", "bn254"),
+        Target::from_args(
+            "poseidon2",
+            "noir-provekit",
+            "../.outputs/benchmark/poseidon2_noir-provekit.csv",
+        ),
+    );
+}
@@ -0,0 +1,17 @@
+#![no_main]
+
+use guests::mlp::{self, MlpInput};
+
+/// Digest of the logits of the model of width `width` for the sample input. A WASM function
+/// returns a single value, so the logits are hashed, and checked against the digest computed by
+/// the host: a mismatch traps, which fails the proof.
+#[no_mangle]
+pub fn mlp(width: usize, expected_digest: i64) -> i64 {
+    let logits = mlp::infer(&MlpInput {
+        width,
+        input: mlp::sample_input(),
+    });
+    let digest = mlp::logits_digest(&logits) as i64;
+    assert_eq!(digest, expected_digest, "unexpected logits");
+    digest
+}
@@ -1,4 +1,4 @@
-use std::{env, fs::File, path::PathBuf, process::Command, time::Duration};
+use std::{env, fs::File, path::PathBuf, process::Command};
 use zk_engine::{
     nova::{
         provider::{ipa_pc, Bn256EngineIPA},
@@ -10,8 +10,12 @@ use zk_engine::{
     wasm_snark::{StepSize, WasmSNARK},
 };
 
-use utils::{bench::benchmark, size};
-type BenchResult = (Duration, usize, usize);
+use utils::{
+    bench::{benchmark, Metrics, Target},
+    mlp_digest,
+    params::{ParamValue, Params},
+    size,
+};
 
 // Curve Cycle to prove/verify on
 pub type E = Bn256EngineIPA;
@@ -35,10 +39,11 @@ struct Cli {
     #[arg(short, long, num_args = 0..)]
     benchmark_args: Vec<String>,
 
-    #[arg(short = 's', long, default_value = "10")]
-    execution_step_size: usize,
+    /// Step sizes to benchmark each argument with.
+    #[arg(short = 's', long, num_args = 1.., default_value = "10")]
+    execution_step_size: Vec<usize>,
 
-    #[arg(short = 's', long, default_value = "10")]
+    #[arg(short, long)]
     memory_step_size: Option<usize>,
 
     #[arg(short, long)]
@@ -101,21 +106,57 @@ fn main() {
         build_guest(&cli.guest);
     }
 
+    let mut axes = vec![
+        (
+            "input".to_string(),
+            cli.benchmark_args
+                .iter()
+                .map(|arg| arg.parse::<ParamValue>().unwrap())
+                .collect(),
+        ),
+        (
+            "execution_step_size".to_string(),
+            cli.execution_step_size
+                .iter()
+                .map(|&step| ParamValue::from(step))
+                .collect(),
+        ),
+    ];
+    if let Some(ms) = cli.memory_step_size {
+        axes.push(("memory_step_size".to_string(), vec![ParamValue::from(ms)]));
+    }
+
     benchmark(
         generate(cli.clone()),
-        &cli.benchmark_args,
-        &format!("../benchmark_outputs/{}_novanet.csv", cli.guest),
+        Params::product(&axes),
+        Target::new(
+            &cli.guest,
+            "novanet",
+            &format!("../benchmark_outputs/{}_novanet.csv", cli.guest),
+        ),
     );
 }
 
-fn generate(cli: Cli) -> impl Fn(String) -> BenchResult {
-    move |n: String| {
-        let func_args = vec![n];
+/// Arguments of the guest function. The guests whose result can be computed natively also take
+/// the expected result, and trap if they compute another one.
+fn func_args(guest: &str, input: &ParamValue) -> Vec<String> {
+    let mut args = vec![input.to_string()];
+    if guest == "mlp" {
+        let width = input.as_u64().expect("The MLP width must be an integer");
+        args.push((mlp_digest(width as usize) as i64).to_string());
+    }
+    args
+}
+
+fn generate(cli: Cli) -> impl Fn(Params) -> Metrics {
+    move |params: Params| {
+        let mut metrics = Metrics::new(params.clone());
+        let func_args = func_args(&cli.guest, params.get("input").unwrap());
 
-        let mut step_size = StepSize::new(cli.execution_step_size);
+        let mut step_size = StepSize::new(params.u64("execution_step_size") as usize);
 
-        if let Some(ms) = cli.memory_step_size {
-            step_size = step_size.set_memory_step_size(ms);
+        if let Some(ms) = params.get("memory_step_size").and_then(ParamValue::as_u64) {
+            step_size = step_size.set_memory_step_size(ms as usize);
         }
 
         // Produce setup material
@@ -148,11 +189,12 @@ fn generate(cli: Cli) -> impl Fn(String) -> BenchResult {
             snark = snark.compress(&pp, &instance).expect("Failed in compress");
         }
 
+        metrics.proof_duration = start.elapsed();
+
         // Verify the proof
+        let start = std::time::Instant::now();
         snark.verify(&pp, &instance).expect("Failed in verify");
-
-        let end = std::time::Instant::now();
-        let duration = end.duration_since(start);
+        metrics.verify_duration = start.elapsed();
 
         // Get execution trace length
         let (execution_trace, _, _) = wasm_ctx
@@ -161,7 +203,9 @@ fn generate(cli: Cli) -> impl Fn(String) -> BenchResult {
 
         println!("Success!");
 
-        (duration, size(&snark), execution_trace.len())
+        metrics.proof_bytes = size(&snark);
+        metrics.cycles = execution_trace.len() as u64;
+        metrics
     }
 }
 
@@ -0,0 +1,16 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use guests::bls;
+
+use openvm::io::{read, reveal_u32};
+
+// ANCHOR: main
+openvm::entry!(main);
+
+pub fn main() {
+    let input: bls::BlsAggregateInput = read();
+    let is_ok = bls::verify_aggregate(&input);
+    reveal_u32(is_ok as u32, 0);
+}
+// ANCHOR_END: main
@@ -2,6 +2,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
+use alloc::vec::Vec;
 use guests::ecdsa;
 
 use openvm::io::read;
@@ -10,8 +11,8 @@ use openvm::io::read;
 openvm::entry!(main);
 
 pub fn main() {
-    let input: ecdsa::EcdsaVerifyInput = read();
-    let is_ok = ecdsa::ecdsa_verify(input);
+    let input: Vec<ecdsa::EcdsaVerifyInput> = read();
+    let is_ok = ecdsa::ecdsa_verify_batch(input);
     if is_ok != true {
         panic!();
     }
@@ -0,0 +1,18 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+use alloc::vec::Vec;
+use guests::ed25519;
+
+use openvm::io::{read, reveal_u32};
+
+// ANCHOR: main
+openvm::entry!(main);
+
+pub fn main() {
+    let input: Vec<ed25519::Ed25519VerifyInput> = read();
+    let is_ok = ed25519::ed25519_verify_batch(input);
+    reveal_u32(is_ok as u32, 0);
+}
+// ANCHOR_END: main
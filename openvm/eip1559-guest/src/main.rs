@@ -0,0 +1,18 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+use alloc::vec::Vec;
+use guests::eip1559;
+
+use openvm::io::{read, reveal_bytes32};
+
+openvm::entry!(main);
+
+pub fn main() {
+    let input: Vec<u8> = read();
+    let sender = eip1559::recover_sender(&input).expect("invalid transaction");
+    let mut output = [0; 32];
+    output[..20].copy_from_slice(&sender);
+    reveal_bytes32(output);
+}
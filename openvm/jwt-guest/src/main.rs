@@ -0,0 +1,17 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use guests::jwt;
+
+use openvm::io::{read, reveal_bytes32};
+
+openvm::entry!(main);
+
+pub fn main() {
+    let input: jwt::JwtInput = read();
+    let subject = jwt::verify_token(&input).expect("invalid token");
+    // The subject is revealed padded with zeros, so it must fit in 32 bytes.
+    let mut output = [0; 32];
+    output[..subject.len()].copy_from_slice(subject.as_bytes());
+    reveal_bytes32(output);
+}
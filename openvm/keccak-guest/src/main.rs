@@ -0,0 +1,18 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::hint::black_box;
+
+use openvm::io::{read, reveal_bytes32};
+use openvm_keccak256_guest::keccak256;
+
+openvm::entry!(main);
+
+pub fn main() {
+    let input: Vec<u8> = read();
+    let output = keccak256(&black_box(input));
+    reveal_bytes32(output);
+}
@@ -0,0 +1,14 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+use guests::merkle;
+
+use openvm::io::{read, reveal_u32};
+
+openvm::entry!(main);
+
+pub fn main() {
+    let input: merkle::MerkleInput = read();
+    reveal_u32(merkle::verify_batch(&input) as u32, 0);
+}
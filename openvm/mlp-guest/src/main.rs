@@ -0,0 +1,18 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use guests::mlp;
+
+use openvm::io::{read, reveal_u32};
+
+// ANCHOR: main
+openvm::entry!(main);
+
+pub fn main() {
+    let input: mlp::MlpInput = read();
+    let logits = mlp::infer(&input);
+    for (index, logit) in logits.into_iter().enumerate() {
+        reveal_u32(logit as u32, index);
+    }
+}
+// ANCHOR_END: main
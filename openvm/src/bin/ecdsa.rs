@@ -10,14 +10,21 @@ use openvm_sdk::{
     Sdk, StdIn,
 };
 use openvm_stark_sdk::config::FriParameters;
-use utils::{bench::benchmark, bench::Metrics, ecdsa_input, metadata::ECDSA_INPUTS, size};
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    ecdsa_input,
+    metadata::ECDSA_INPUTS,
+    profile::{phase, Phase},
+    size,
+};
 
 #[allow(unused_variables, unused_doc_comments)]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     benchmark(
         benchmark_ecdsa,
         &ECDSA_INPUTS,
-        "../.outputs/benchmark/ecdsa_openvm.csv",
+        Target::from_args("ecdsa", "openvm", "../.outputs/benchmark/ecdsa_openvm.csv"),
     );
 
     Ok(())
@@ -38,7 +45,8 @@ fn benchmark_ecdsa(n: usize) -> Metrics {
     // 1. Build the VmConfig with the extensions needed.
     let sdk = Sdk;
 
-    // 2a. Build the ELF with guest options and a target filter.
+    // 2a. Build the ELF with guest options and a target filter. This compiles the guest with
+    // cargo, so it is left out of the Load phase.
     let guest_opts = GuestOptions::default();
     let target_path = "ecdsa-guest";
     let elf = sdk
@@ -48,10 +56,13 @@ fn benchmark_ecdsa(n: usize) -> Metrics {
 
     // ANCHOR: transpilation
     // 3. Transpile the ELF into a VmExe
-    let exe = sdk.transpile(elf.clone(), vm_config.transpiler()).unwrap();
+    let exe = phase(Phase::Load, || {
+        sdk.transpile(elf.clone(), vm_config.transpiler()).unwrap()
+    });
+    metrics.program_bytes = size(&exe);
     // ANCHOR_END: transpilation
 
-    let input = ecdsa_input();
+    let input = ecdsa_input(n);
 
     // ANCHOR: execution
     // 4. Format your input into StdIn
@@ -60,9 +71,10 @@ fn benchmark_ecdsa(n: usize) -> Metrics {
 
     // 5. Run the program
     let start = Instant::now();
-    let _ = sdk
-        .execute(exe.clone(), vm_config.clone(), stdin.clone())
-        .unwrap();
+    let _ = phase(Phase::Execute, || {
+        sdk.execute(exe.clone(), vm_config.clone(), stdin.clone())
+            .unwrap()
+    });
     metrics.exec_duration = start.elapsed();
     // ANCHOR_END: execution
 
@@ -73,10 +85,16 @@ fn benchmark_ecdsa(n: usize) -> Metrics {
     let app_config = AppConfig::new(app_fri_params, vm_config);
 
     // 7. Commit the exe
-    let app_committed_exe = sdk.commit_app_exe(app_fri_params, exe).unwrap();
+    let app_committed_exe = phase(Phase::Setup, || {
+        sdk.commit_app_exe(app_fri_params, exe).unwrap()
+    });
 
     // 8. Generate an AppProvingKey
-    let app_pk = Arc::new(sdk.app_keygen(app_config).unwrap());
+    let app_pk = phase(Phase::Setup, || {
+        Arc::new(sdk.app_keygen(app_config).unwrap())
+    });
+    metrics.pk_bytes = size(app_pk.as_ref());
+    metrics.committed_program_bytes = size(app_committed_exe.as_ref());
 
     // 9a. Generate a proof
     // let proof = sdk.generate_app_proof(app_pk.clone(), app_committed_exe.clone(), stdin.clone()).unwrap();
@@ -84,7 +102,9 @@ fn benchmark_ecdsa(n: usize) -> Metrics {
     let app_prover = AppProver::new(app_pk.app_vm_pk.clone(), app_committed_exe.clone())
         .with_program_name("test_program");
     let start: Instant = Instant::now();
-    let proof = app_prover.generate_app_proof(stdin.clone());
+    let proof = phase(Phase::Prove, || {
+        app_prover.generate_app_proof(stdin.clone())
+    });
     // ANCHOR_END: proof_generation
     metrics.proof_duration = start.elapsed();
     metrics.proof_bytes = size(&proof);
@@ -92,8 +112,11 @@ fn benchmark_ecdsa(n: usize) -> Metrics {
     // ANCHOR: verification
     // 10. Verify your program
     let app_vk = app_pk.get_app_vk();
+    metrics.vk_bytes = size(&app_vk);
     let start = Instant::now();
-    sdk.verify_app_proof(&app_vk, &proof).unwrap();
+    phase(Phase::Verify, || {
+        sdk.verify_app_proof(&app_vk, &proof).unwrap()
+    });
     metrics.verify_duration = start.elapsed();
     // ANCHOR_END: verification
     metrics
@@ -0,0 +1,129 @@
+// ANCHOR: dependencies
+use std::sync::Arc;
+use std::time::Instant;
+
+use eyre::Result;
+use openvm_build::GuestOptions;
+use openvm_sdk::{
+    config::{AppConfig, SdkVmConfig},
+    prover::AppProver,
+    Sdk, StdIn,
+};
+use openvm_stark_sdk::config::FriParameters;
+use openvm_stark_sdk::openvm_stark_backend::p3_field::PrimeField32;
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    ed25519_input,
+    metadata::ED25519_INPUTS,
+    profile::{phase, Phase},
+    size,
+};
+
+#[allow(unused_variables, unused_doc_comments)]
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    benchmark(
+        benchmark_ed25519,
+        &ED25519_INPUTS,
+        Target::from_args(
+            "ed25519",
+            "openvm",
+            "../.outputs/benchmark/ed25519_openvm.csv",
+        ),
+    );
+
+    Ok(())
+}
+
+fn benchmark_ed25519(n: usize) -> Metrics {
+    let mut metrics: Metrics = Metrics::new(n as usize);
+    // ANCHOR: vm_config
+    let vm_config = SdkVmConfig::builder()
+        .system(Default::default())
+        .rv32i(Default::default())
+        .rv32m(Default::default())
+        .io(Default::default())
+        .build();
+    // ANCHOR_END: vm_config
+
+    // ANCHOR: build
+    // 1. Build the VmConfig with the extensions needed.
+    let sdk = Sdk;
+
+    // 2a. Build the ELF with guest options and a target filter. This compiles the guest with
+    // cargo, so it is left out of the Load phase.
+    let guest_opts = GuestOptions::default();
+    let target_path = "ed25519-guest";
+    let elf = sdk
+        .build(guest_opts, target_path, &Default::default())
+        .unwrap();
+    // ANCHOR_END: build
+
+    // ANCHOR: transpilation
+    // 3. Transpile the ELF into a VmExe
+    let exe = phase(Phase::Load, || {
+        sdk.transpile(elf.clone(), vm_config.transpiler()).unwrap()
+    });
+    metrics.program_bytes = size(&exe);
+    // ANCHOR_END: transpilation
+
+    let input = ed25519_input(n);
+
+    // ANCHOR: execution
+    // 4. Format your input into StdIn
+    let mut stdin = StdIn::default();
+    stdin.write(&input);
+
+    // 5. Run the program
+    let start = Instant::now();
+    let public_values = phase(Phase::Execute, || {
+        sdk.execute(exe.clone(), vm_config.clone(), stdin.clone())
+            .unwrap()
+    });
+    metrics.exec_duration = start.elapsed();
+    assert_eq!(public_values[0].as_canonical_u32(), 1, "invalid signatures");
+    // ANCHOR_END: execution
+
+    // ANCHOR: proof_generation
+    // 6. Set app configuration
+    let app_log_blowup = 2;
+    let app_fri_params = FriParameters::standard_with_100_bits_conjectured_security(app_log_blowup);
+    let app_config = AppConfig::new(app_fri_params, vm_config);
+
+    // 7. Commit the exe
+    let app_committed_exe = phase(Phase::Setup, || {
+        sdk.commit_app_exe(app_fri_params, exe).unwrap()
+    });
+
+    // 8. Generate an AppProvingKey
+    let app_pk = phase(Phase::Setup, || {
+        Arc::new(sdk.app_keygen(app_config).unwrap())
+    });
+    metrics.pk_bytes = size(app_pk.as_ref());
+    metrics.committed_program_bytes = size(app_committed_exe.as_ref());
+
+    // 9a. Generate a proof
+    // let proof = sdk.generate_app_proof(app_pk.clone(), app_committed_exe.clone(), stdin.clone()).unwrap();
+    // 9b. Generate a proof with an AppProver with custom fields
+    let app_prover = AppProver::new(app_pk.app_vm_pk.clone(), app_committed_exe.clone())
+        .with_program_name("test_program");
+    let start: Instant = Instant::now();
+    let proof = phase(Phase::Prove, || {
+        app_prover.generate_app_proof(stdin.clone())
+    });
+    // ANCHOR_END: proof_generation
+    metrics.proof_duration = start.elapsed();
+    metrics.proof_bytes = size(&proof);
+
+    // ANCHOR: verification
+    // 10. Verify your program
+    let app_vk = app_pk.get_app_vk();
+    metrics.vk_bytes = size(&app_vk);
+    let start = Instant::now();
+    phase(Phase::Verify, || {
+        sdk.verify_app_proof(&app_vk, &proof).unwrap()
+    });
+    metrics.verify_duration = start.elapsed();
+    // ANCHOR_END: verification
+    metrics
+}
@@ -10,7 +10,13 @@ use openvm_sdk::{
     Sdk, StdIn,
 };
 use openvm_stark_sdk::config::{baby_bear_poseidon2::BabyBearPoseidon2Engine, FriParameters};
-use utils::{bench::benchmark, bench::Metrics, metadata::FIBONACCI_INPUTS, size};
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    metadata::FIBONACCI_INPUTS,
+    profile::{phase, Phase},
+    size,
+};
 
 // ANCHOR_END: dependencies
 
@@ -19,7 +25,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     benchmark(
         benchmark_fib,
         &FIBONACCI_INPUTS,
-        "../.outputs/benchmark/fib_openvm.csv",
+        Target::from_args("fib", "openvm", "../.outputs/benchmark/fib_openvm.csv"),
     );
 
     Ok(())
@@ -39,9 +45,10 @@ fn benchmark_fib(n: u32) -> Metrics {
 
     // ANCHOR: build
     // 1. Build the VmConfig with the extensions needed.
-    let sdk = Sdk::new();
+    let sdk = phase(Phase::Init, || Sdk::new());
 
-    // 2a. Build the ELF with guest options and a target filter.
+    // 2a. Build the ELF with guest options and a target filter. This compiles the guest with
+    // cargo, so it is left out of the Load phase.
     let guest_opts = GuestOptions::default();
     let target_path = "fibonacci-guest";
     let elf = sdk
@@ -51,7 +58,10 @@ fn benchmark_fib(n: u32) -> Metrics {
 
     // ANCHOR: transpilation
     // 3. Transpile the ELF into a VmExe
-    let exe = sdk.transpile(elf.clone(), vm_config.transpiler()).unwrap();
+    let exe = phase(Phase::Load, || {
+        sdk.transpile(elf.clone(), vm_config.transpiler()).unwrap()
+    });
+    metrics.program_bytes = size(&exe);
     // ANCHOR_END: transpilation
 
     // ANCHOR: execution
@@ -61,13 +71,20 @@ fn benchmark_fib(n: u32) -> Metrics {
 
     // 5. Run the program
     let start = Instant::now();
-    let output = sdk
-        .execute(exe.clone(), vm_config.clone(), stdin.clone())
-        .unwrap();
+    let output = phase(Phase::Execute, || {
+        sdk.execute(exe.clone(), vm_config.clone(), stdin.clone())
+            .unwrap()
+    });
     metrics.exec_duration = start.elapsed();
     // Compare the first u32 output with the expected result.
-    let bytes: Vec<u8> = output.into_iter()
-        .map(|field| field.to_string().parse::<u8>().expect("Failed to parse field to byte"))
+    let bytes: Vec<u8> = output
+        .into_iter()
+        .map(|field| {
+            field
+                .to_string()
+                .parse::<u8>()
+                .expect("Failed to parse field to byte")
+        })
         .collect();
 
     assert_eq!(bytes[0..4], guests::fib::fib(n).to_le_bytes().to_vec());
@@ -77,22 +94,34 @@ fn benchmark_fib(n: u32) -> Metrics {
     // ANCHOR: proof_generation
     // 6. Set app configuration
     let log_blowup_factor = 1;
-    let app_fri_params = FriParameters::standard_with_100_bits_conjectured_security(log_blowup_factor);
+    let app_fri_params =
+        FriParameters::standard_with_100_bits_conjectured_security(log_blowup_factor);
     let app_config = AppConfig::new(app_fri_params, vm_config);
 
     // 7. Commit the exe
-    let app_committed_exe = sdk.commit_app_exe(app_fri_params, exe).unwrap();
+    let app_committed_exe = phase(Phase::Setup, || {
+        sdk.commit_app_exe(app_fri_params, exe).unwrap()
+    });
 
     // 8. Generate an AppProvingKey
-    let app_pk = Arc::new(sdk.app_keygen(app_config).unwrap());
+    let app_pk = phase(Phase::Setup, || {
+        Arc::new(sdk.app_keygen(app_config).unwrap())
+    });
+    metrics.pk_bytes = size(app_pk.as_ref());
+    metrics.committed_program_bytes = size(app_committed_exe.as_ref());
 
     // 9a. Generate a proof
     // let proof = sdk.generate_app_proof(app_pk.clone(), app_committed_exe.clone(), stdin.clone()).unwrap();
     // 9b. Generate a proof with an AppProver with custom fields
-    let app_prover = AppProver::<_, BabyBearPoseidon2Engine>::new(app_pk.app_vm_pk.clone(), app_committed_exe.clone())
-        .with_program_name("fibonacci");
+    let app_prover = AppProver::<_, BabyBearPoseidon2Engine>::new(
+        app_pk.app_vm_pk.clone(),
+        app_committed_exe.clone(),
+    )
+    .with_program_name("fibonacci");
     let start = Instant::now();
-    let proof = app_prover.generate_app_proof(stdin.clone());
+    let proof = phase(Phase::Prove, || {
+        app_prover.generate_app_proof(stdin.clone())
+    });
     // ANCHOR_END: proof_generation
     metrics.proof_duration = start.elapsed();
     metrics.proof_bytes = size(&proof);
@@ -100,8 +129,11 @@ fn benchmark_fib(n: u32) -> Metrics {
     // ANCHOR: verification
     // 10. Verify your program
     let app_vk = app_pk.get_app_vk();
+    metrics.vk_bytes = size(&app_vk);
     let start = Instant::now();
-    sdk.verify_app_proof(&app_vk, &proof).unwrap();
+    phase(Phase::Verify, || {
+        sdk.verify_app_proof(&app_vk, &proof).unwrap()
+    });
     metrics.verify_duration = start.elapsed();
     // ANCHOR_END: verification
 
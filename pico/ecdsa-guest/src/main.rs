@@ -1,12 +1,12 @@
 #![no_main]
 
 pico_sdk::entrypoint!(main);
-use guests::ecdsa::{ecdsa_verify, EcdsaVerifyInput};
+use guests::ecdsa::{ecdsa_verify_batch, EcdsaVerifyInput};
 use pico_sdk::io::{commit, read_as};
 
 pub fn main() {
-    let input = read_as::<EcdsaVerifyInput>();
-    let result = ecdsa_verify(input);
+    let input = read_as::<Vec<EcdsaVerifyInput>>();
+    let result = ecdsa_verify_batch(input);
     assert!(result, "Signature verification failed");
     commit(&result);
 }
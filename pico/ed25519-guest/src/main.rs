@@ -0,0 +1,12 @@
+#![no_main]
+
+pico_sdk::entrypoint!(main);
+use guests::ed25519::{ed25519_verify_batch, Ed25519VerifyInput};
+use pico_sdk::io::{commit, read_as};
+
+pub fn main() {
+    let input = read_as::<Vec<Ed25519VerifyInput>>();
+    let result = ed25519_verify_batch(input);
+    assert!(result, "Signature verification failed");
+    commit(&result);
+}
@@ -0,0 +1,11 @@
+#![no_main]
+
+pico_sdk::entrypoint!(main);
+use guests::eip1559;
+use pico_sdk::io::{commit, read_as};
+
+pub fn main() {
+    let input = read_as::<Vec<u8>>();
+    let sender = eip1559::recover_sender(&input).expect("invalid transaction");
+    commit(&sender);
+}
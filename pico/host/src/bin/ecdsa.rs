@@ -1,14 +1,19 @@
 use pico_sdk::{client::DefaultProverClient, init_logger};
 use std::time::Instant;
 use utils::{
-    bench::Metrics, bench::benchmark, ecdsa_input, load_elf, metadata::ECDSA_INPUTS, size,
+    bench::Metrics,
+    bench::{Target, benchmark},
+    ecdsa_input, load_elf,
+    metadata::ECDSA_INPUTS,
+    profile::{Phase, phase},
+    size,
 };
 
 fn main() {
     benchmark(
         bench_ecdsa,
         &ECDSA_INPUTS,
-        "../.outputs/benchmark/ecdsa_pico.csv",
+        Target::from_args("ecdsa", "pico", "../.outputs/benchmark/ecdsa_pico.csv"),
     );
 }
 
@@ -16,15 +21,20 @@ fn bench_ecdsa(n: usize) -> Metrics {
     let mut metrics: Metrics = Metrics::new(n as usize);
 
     init_logger();
-    let elf = load_elf("./ecdsa-guest/elf/riscv32im-pico-zkvm-elf");
-    let client = DefaultProverClient::new(&elf);
+    let elf = phase(Phase::Load, || {
+        load_elf("./ecdsa-guest/elf/riscv32im-pico-zkvm-elf")
+    });
+    let client = phase(Phase::Init, || DefaultProverClient::new(&elf));
+    metrics.program_bytes = elf.len();
     let stdin_builder = client.get_stdin_builder();
 
-    let input = ecdsa_input();
+    let input = ecdsa_input(n);
     stdin_builder.borrow_mut().write(&input);
 
     let now = Instant::now();
-    let proof = client.prove_fast().expect("Failed to generate proof");
+    let proof = phase(Phase::Prove, || {
+        client.prove_fast().expect("Failed to generate proof")
+    });
     metrics.proof_duration = now.elapsed();
     metrics.proof_bytes = size(&proof.proofs);
 
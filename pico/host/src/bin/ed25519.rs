@@ -0,0 +1,47 @@
+use pico_sdk::{client::DefaultProverClient, init_logger};
+use std::time::Instant;
+use utils::{
+    bench::Metrics,
+    bench::{Target, benchmark},
+    ed25519_input, load_elf,
+    metadata::ED25519_INPUTS,
+    profile::{Phase, phase},
+    size,
+};
+
+fn main() {
+    benchmark(
+        bench_ed25519,
+        &ED25519_INPUTS,
+        Target::from_args("ed25519", "pico", "../.outputs/benchmark/ed25519_pico.csv"),
+    );
+}
+
+fn bench_ed25519(n: usize) -> Metrics {
+    let mut metrics: Metrics = Metrics::new(n as usize);
+
+    init_logger();
+    let elf = phase(Phase::Load, || {
+        load_elf("./ed25519-guest/elf/riscv32im-pico-zkvm-elf")
+    });
+    let client = phase(Phase::Init, || DefaultProverClient::new(&elf));
+    metrics.program_bytes = elf.len();
+    let stdin_builder = client.get_stdin_builder();
+
+    let input = ed25519_input(n);
+    stdin_builder.borrow_mut().write(&input);
+
+    let now = Instant::now();
+    let proof = phase(Phase::Prove, || {
+        client.prove_fast().expect("Failed to generate proof")
+    });
+    metrics.proof_duration = now.elapsed();
+    metrics.proof_bytes = size(&proof.proofs);
+    assert_eq!(
+        proof.pv_stream.as_deref(),
+        Some(&[1][..]),
+        "invalid signatures"
+    );
+
+    metrics
+}
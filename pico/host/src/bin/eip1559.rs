@@ -0,0 +1,46 @@
+use pico_sdk::{client::DefaultProverClient, init_logger};
+use std::time::Instant;
+use utils::{
+    bench::Metrics,
+    bench::{Target, benchmark},
+    eip1559_input, eip1559_sender, load_elf,
+    metadata::EIP1559_INPUTS,
+    profile::{Phase, phase},
+    size,
+};
+
+fn main() {
+    benchmark(
+        bench_eip1559,
+        &EIP1559_INPUTS,
+        Target::from_args("eip1559", "pico", "../.outputs/benchmark/eip1559_pico.csv"),
+    );
+}
+
+fn bench_eip1559(calldata_bytes: usize) -> Metrics {
+    let mut metrics: Metrics = Metrics::new(calldata_bytes as usize);
+
+    init_logger();
+    let elf = phase(Phase::Load, || {
+        load_elf("./eip1559-guest/elf/riscv32im-pico-zkvm-elf")
+    });
+    let client = phase(Phase::Init, || DefaultProverClient::new(&elf));
+    metrics.program_bytes = elf.len();
+    let stdin_builder = client.get_stdin_builder();
+
+    let input = eip1559_input(calldata_bytes);
+    stdin_builder.borrow_mut().write(&input);
+
+    let now = Instant::now();
+    let proof = phase(Phase::Prove, || {
+        client.prove_fast().expect("Failed to generate proof")
+    });
+    metrics.proof_duration = now.elapsed();
+    metrics.proof_bytes = size(&proof.proofs);
+    assert_eq!(
+        proof.pv_stream.as_deref(),
+        Some(&eip1559_sender(calldata_bytes)[..])
+    );
+
+    metrics
+}
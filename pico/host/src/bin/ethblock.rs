@@ -1,13 +1,23 @@
 use pico_sdk::{client::DefaultProverClient, init_logger};
 use std::time::Instant;
-use utils::{bench::Metrics, bench::benchmark, load_elf, size};
+use utils::{
+    bench::Metrics,
+    bench::{Target, benchmark},
+    load_elf,
+    profile::{Phase, phase},
+    size,
+};
 
 fn main() {
     let lengths = [1];
     benchmark(
         bench_ethblock,
         &lengths,
-        "../.outputs/benchmark/ethblock_pico.csv",
+        Target::from_args(
+            "ethblock",
+            "pico",
+            "../.outputs/benchmark/ethblock_pico.csv",
+        ),
     );
 }
 
@@ -15,13 +25,18 @@ fn bench_ethblock(num_txs: usize) -> Metrics {
     let mut metrics: Metrics = Metrics::new(num_txs);
 
     init_logger();
-    let elf = load_elf("./ethblock-guest/elf/riscv32im-pico-zkvm-elf");
-    let client = DefaultProverClient::new(&elf);
+    let elf = phase(Phase::Load, || {
+        load_elf("./ethblock-guest/elf/riscv32im-pico-zkvm-elf")
+    });
+    let client = phase(Phase::Init, || DefaultProverClient::new(&elf));
+    metrics.program_bytes = elf.len();
     let stdin_builder = client.get_stdin_builder();
     stdin_builder.borrow_mut().write(&num_txs);
 
     let now = Instant::now();
-    let proof = client.prove_fast().expect("Failed to generate proof");
+    let proof = phase(Phase::Prove, || {
+        client.prove_fast().expect("Failed to generate proof")
+    });
     metrics.proof_duration = now.elapsed();
     metrics.proof_bytes = size(&proof.proofs);
 
@@ -0,0 +1,43 @@
+use pico_sdk::{client::DefaultProverClient, init_logger};
+use std::time::Instant;
+use utils::{
+    bench::Metrics,
+    bench::{Target, benchmark},
+    keccak_digest, keccak_input, load_elf,
+    metadata::KECCAK_INPUTS,
+    profile::{Phase, phase},
+    size,
+};
+
+fn main() {
+    benchmark(
+        bench_hash,
+        &KECCAK_INPUTS,
+        Target::from_args("keccak", "pico", "../.outputs/benchmark/keccak_pico.csv"),
+    );
+}
+
+fn bench_hash(num_bytes: usize) -> Metrics {
+    let mut metrics: Metrics = Metrics::new(num_bytes as usize);
+
+    init_logger();
+    let elf = phase(Phase::Load, || {
+        load_elf("./keccak-guest/elf/riscv32im-pico-zkvm-elf")
+    });
+    let client = phase(Phase::Init, || DefaultProverClient::new(&elf));
+    metrics.program_bytes = elf.len();
+    let stdin_builder = client.get_stdin_builder();
+
+    let input = keccak_input(num_bytes);
+    stdin_builder.borrow_mut().write(&input);
+
+    let now = Instant::now();
+    let proof = phase(Phase::Prove, || {
+        client.prove_fast().expect("Failed to generate proof")
+    });
+    metrics.proof_duration = now.elapsed();
+    metrics.proof_bytes = size(&proof.proofs);
+    assert_eq!(proof.pv_stream.as_deref(), Some(&keccak_digest(&input)[..]));
+
+    metrics
+}
@@ -0,0 +1,47 @@
+use pico_sdk::{client::DefaultProverClient, init_logger};
+use std::time::Instant;
+use utils::{
+    bench::Metrics,
+    bench::{Target, benchmark},
+    load_elf, merkle_input, merkle_params,
+    params::Params,
+    profile::{Phase, phase},
+    size,
+};
+
+fn main() {
+    benchmark(
+        bench_merkle,
+        merkle_params(),
+        Target::from_args("merkle", "pico", "../.outputs/benchmark/merkle_pico.csv"),
+    );
+}
+
+fn bench_merkle(params: Params) -> Metrics {
+    let mut metrics: Metrics = Metrics::new(params.clone());
+
+    init_logger();
+    let elf = phase(Phase::Load, || {
+        load_elf("./merkle-guest/elf/riscv32im-pico-zkvm-elf")
+    });
+    let client = phase(Phase::Init, || DefaultProverClient::new(&elf));
+    metrics.program_bytes = elf.len();
+    let stdin_builder = client.get_stdin_builder();
+
+    let input = merkle_input(&params);
+    stdin_builder.borrow_mut().write(&input);
+
+    let now = Instant::now();
+    let proof = phase(Phase::Prove, || {
+        client.prove_fast().expect("Failed to generate proof")
+    });
+    metrics.proof_duration = now.elapsed();
+    metrics.proof_bytes = size(&proof.proofs);
+    assert_eq!(
+        proof.pv_stream.as_deref(),
+        Some(&[1][..]),
+        "invalid inclusion proofs"
+    );
+
+    metrics
+}
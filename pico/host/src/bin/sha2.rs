@@ -1,12 +1,19 @@
 use pico_sdk::{client::DefaultProverClient, init_logger};
 use std::time::Instant;
-use utils::{bench::Metrics, bench::benchmark, load_elf, metadata::SHA2_INPUTS, sha2_input, size};
+use utils::{
+    bench::Metrics,
+    bench::{Target, benchmark},
+    load_elf,
+    metadata::SHA2_INPUTS,
+    profile::{Phase, phase},
+    sha2_input, size,
+};
 
 fn main() {
     benchmark(
         bench_hash,
         &SHA2_INPUTS,
-        "../.outputs/benchmark/sha2_pico.csv",
+        Target::from_args("sha2", "pico", "../.outputs/benchmark/sha2_pico.csv"),
     );
 }
 
@@ -14,15 +21,20 @@ fn bench_hash(num_bytes: usize) -> Metrics {
     let mut metrics: Metrics = Metrics::new(num_bytes as usize);
 
     init_logger();
-    let elf = load_elf("./sha2-guest/elf/riscv32im-pico-zkvm-elf");
-    let client = DefaultProverClient::new(&elf);
+    let elf = phase(Phase::Load, || {
+        load_elf("./sha2-guest/elf/riscv32im-pico-zkvm-elf")
+    });
+    let client = phase(Phase::Init, || DefaultProverClient::new(&elf));
+    metrics.program_bytes = elf.len();
     let stdin_builder = client.get_stdin_builder();
 
     let input = sha2_input(num_bytes);
     stdin_builder.borrow_mut().write(&input);
 
     let now = Instant::now();
-    let proof = client.prove_fast().expect("Failed to generate proof");
+    let proof = phase(Phase::Prove, || {
+        client.prove_fast().expect("Failed to generate proof")
+    });
     metrics.proof_duration = now.elapsed();
     metrics.proof_bytes = size(&proof.proofs);
 
@@ -1,12 +1,23 @@
 use pico_sdk::{client::DefaultProverClient, init_logger};
 use std::time::Instant;
-use utils::{bench::Metrics, bench::benchmark, load_elf, metadata::ETHTRANSFER_INPUTS, size};
+use utils::{
+    bench::Metrics,
+    bench::{Target, benchmark},
+    load_elf,
+    metadata::ETHTRANSFER_INPUTS,
+    profile::{Phase, phase},
+    size,
+};
 
 fn main() {
     benchmark(
         bench_transfer_eth,
         &ETHTRANSFER_INPUTS,
-        "../.outputs/benchmark/ethtransfer_pico.csv",
+        Target::from_args(
+            "ethtransfer",
+            "pico",
+            "../.outputs/benchmark/ethtransfer_pico.csv",
+        ),
     );
 }
 
@@ -14,13 +25,18 @@ fn bench_transfer_eth(n: usize) -> Metrics {
     let mut metrics: Metrics = Metrics::new(n as usize);
 
     init_logger();
-    let elf = load_elf("./transfer-eth-guest/elf/riscv32im-pico-zkvm-elf");
-    let client = DefaultProverClient::new(&elf);
+    let elf = phase(Phase::Load, || {
+        load_elf("./transfer-eth-guest/elf/riscv32im-pico-zkvm-elf")
+    });
+    let client = phase(Phase::Init, || DefaultProverClient::new(&elf));
+    metrics.program_bytes = elf.len();
     let stdin_builder = client.get_stdin_builder();
     stdin_builder.borrow_mut().write(&n);
 
     let now = Instant::now();
-    let proof = client.prove_fast().expect("Failed to generate proof");
+    let proof = phase(Phase::Prove, || {
+        client.prove_fast().expect("Failed to generate proof")
+    });
     metrics.proof_duration = now.elapsed();
     metrics.proof_bytes = size(&proof.proofs);
 
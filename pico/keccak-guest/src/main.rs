@@ -0,0 +1,11 @@
+#![no_main]
+
+pico_sdk::entrypoint!(main);
+use guests::keccak;
+use pico_sdk::io::{commit, read_as};
+
+pub fn main() {
+    let input = read_as::<Vec<u8>>();
+    let result = keccak::keccak(&input);
+    commit(&result);
+}
@@ -0,0 +1,12 @@
+#![no_main]
+
+pico_sdk::entrypoint!(main);
+use guests::merkle::{MerkleInput, verify_batch};
+use pico_sdk::io::{commit, read_as};
+
+pub fn main() {
+    let input = read_as::<MerkleInput>();
+    let result = verify_batch(&input);
+    assert!(result, "Inclusion proof verification failed");
+    commit(&result);
+}
@@ -0,0 +1,29 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_main]
+
+use guests::bls;
+use risc0_zkvm::guest::env;
+
+risc0_zkvm::guest::entry!(main);
+
+fn main() {
+    // Decode the public keys, message, and aggregate signature from the inputs.
+    let input: bls::BlsAggregateInput = env::read();
+    // Verify the aggregate signature against all the public keys.
+    let result = bls::verify_aggregate(&input);
+    // Commit to the journal whether the signature is valid.
+    env::commit(&result);
+}
@@ -20,10 +20,10 @@ use risc0_zkvm::guest::env;
 risc0_zkvm::guest::entry!(main);
 
 fn main() {
-    // Decode the verifying key, message, and signature from the inputs.
+    // Decode the verifying keys, messages, and signatures from the inputs.
     let input = env::read();
-    // Verify the signature, panicking if verification fails.
-    let result = ecdsa::ecdsa_verify(input);
-    // Commit to the journal the verifying key and message that was signed.
+    // Verify every signature of the batch.
+    let result = ecdsa::ecdsa_verify_batch(input);
+    // Commit to the journal whether the whole batch is valid.
     env::commit(&result);
 }
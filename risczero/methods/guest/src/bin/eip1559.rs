@@ -0,0 +1,8 @@
+use guests::eip1559;
+use risc0_zkvm::guest::env;
+
+fn main() {
+    let transaction: Vec<u8> = env::read();
+    let sender = eip1559::recover_sender(&transaction).expect("invalid transaction");
+    env::commit(&sender)
+}
@@ -0,0 +1,8 @@
+use guests::jwt;
+use risc0_zkvm::guest::env;
+
+fn main() {
+    let input: jwt::JwtInput = env::read();
+    let subject = jwt::verify_token(&input).expect("invalid token");
+    env::commit(&subject)
+}
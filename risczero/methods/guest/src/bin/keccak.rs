@@ -0,0 +1,8 @@
+use guests::keccak;
+use risc0_zkvm::guest::env;
+
+fn main() {
+    let data: Vec<u8> = env::read();
+    let hash = keccak::keccak(&data);
+    env::commit(&hash)
+}
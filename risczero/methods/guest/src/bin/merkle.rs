@@ -0,0 +1,8 @@
+use guests::merkle;
+use risc0_zkvm::guest::env;
+
+fn main() {
+    let input: merkle::MerkleInput = env::read();
+    let result = merkle::verify_batch(&input);
+    env::commit(&result)
+}
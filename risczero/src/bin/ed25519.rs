@@ -0,0 +1,66 @@
+use std::time::Instant;
+
+use risc0_zkvm::serde::to_vec;
+use risc0_zkvm::{
+    get_prover_server, sha::Digest, ExecutorEnv, ExecutorImpl, ProverOpts, VerifierContext,
+};
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    ed25519_input,
+    metadata::ED25519_INPUTS,
+    profile::{phase, Phase},
+};
+
+pub fn main() {
+    let gpu = if cfg!(feature = "cuda") { "-gpu" } else { "" };
+    benchmark(
+        benchmark_ed25519,
+        &ED25519_INPUTS,
+        Target::from_args(
+            "ed25519",
+            &format!("risczero{}", gpu),
+            &format!("../.outputs/benchmark/ed25519_risczero{}.csv", gpu),
+        ),
+    );
+}
+
+fn benchmark_ed25519(input: usize) -> Metrics {
+    let mut metrics = Metrics::new(input);
+    const ELF: &[u8] = risc0_benchmark_methods::ED25519_VERIFY_ELF;
+    let image_id: Digest = risc0_benchmark_methods::ED25519_VERIFY_ID.into();
+    metrics.program_bytes = ELF.len();
+    // There are no proving keys, the image ID is all a verifier needs.
+    metrics.vk_bytes = image_id.as_bytes().len();
+    let ed25519_input = ed25519_input(input);
+    let input = to_vec(&ed25519_input).unwrap();
+
+    let env = ExecutorEnv::builder().write_slice(&input).build().unwrap();
+    let mut exec = phase(Phase::Load, || ExecutorImpl::from_elf(env, &ELF).unwrap());
+    let start = Instant::now();
+    let session = phase(Phase::Execute, || exec.run().unwrap());
+    metrics.exec_duration = start.elapsed();
+    metrics.cycles = session.user_cycles;
+
+    let prover = phase(Phase::Init, || {
+        get_prover_server(&ProverOpts::succinct()).unwrap()
+    });
+    let ctx = VerifierContext::default();
+
+    let start = Instant::now();
+    let receipt = phase(Phase::Prove, || {
+        prover.prove_session(&ctx, &session).unwrap().receipt
+    });
+    metrics.proof_duration = start.elapsed();
+    metrics.proof_bytes = receipt.inner.succinct().unwrap().seal_size();
+    assert!(
+        receipt.journal.decode::<bool>().unwrap(),
+        "invalid signatures"
+    );
+
+    let start = Instant::now();
+    phase(Phase::Verify, || receipt.verify(image_id).unwrap());
+    metrics.verify_duration = start.elapsed();
+
+    metrics
+}
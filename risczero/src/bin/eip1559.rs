@@ -0,0 +1,65 @@
+use risc0_zkvm::serde::to_vec;
+use risc0_zkvm::{
+    get_prover_server, sha::Digest, ExecutorEnv, ExecutorImpl, ProverOpts, VerifierContext,
+};
+use std::time::Instant;
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    eip1559_input, eip1559_sender,
+    metadata::EIP1559_INPUTS,
+    profile::{phase, Phase},
+};
+
+pub fn main() {
+    let gpu = if cfg!(feature = "cuda") { "-gpu" } else { "" };
+    benchmark(
+        benchmark_eip1559,
+        &EIP1559_INPUTS,
+        Target::from_args(
+            "eip1559",
+            &format!("risczero{}", gpu),
+            &format!("../.outputs/benchmark/eip1559_risczero{}.csv", gpu),
+        ),
+    );
+}
+
+fn benchmark_eip1559(calldata_bytes: usize) -> Metrics {
+    let mut metrics = Metrics::new(calldata_bytes);
+    const ELF: &[u8] = risc0_benchmark_methods::EIP1559_ELF;
+    let image_id: Digest = risc0_benchmark_methods::EIP1559_ID.into();
+    metrics.program_bytes = ELF.len();
+    // There are no proving keys, the image ID is all a verifier needs.
+    metrics.vk_bytes = image_id.as_bytes().len();
+    let transaction = eip1559_input(calldata_bytes);
+    let input = to_vec(&transaction).unwrap();
+
+    let env = ExecutorEnv::builder().write_slice(&input).build().unwrap();
+    let mut exec = phase(Phase::Load, || ExecutorImpl::from_elf(env, &ELF).unwrap());
+    let start = Instant::now();
+    let session = phase(Phase::Execute, || exec.run().unwrap());
+    metrics.exec_duration = start.elapsed();
+    metrics.cycles = session.user_cycles;
+
+    let prover = phase(Phase::Init, || {
+        get_prover_server(&ProverOpts::succinct()).unwrap()
+    });
+    let ctx = VerifierContext::default();
+
+    let start = Instant::now();
+    let receipt = phase(Phase::Prove, || {
+        prover.prove_session(&ctx, &session).unwrap().receipt
+    });
+    metrics.proof_duration = start.elapsed();
+    assert_eq!(
+        receipt.journal.decode::<[u8; 20]>().unwrap(),
+        eip1559_sender(calldata_bytes)
+    );
+    metrics.proof_bytes = receipt.inner.succinct().unwrap().seal_size();
+
+    let start = Instant::now();
+    phase(Phase::Verify, || receipt.verify(image_id).unwrap());
+    metrics.verify_duration = start.elapsed();
+
+    metrics
+}
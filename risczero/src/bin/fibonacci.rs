@@ -18,40 +18,56 @@ use risc0_zkvm::serde::to_vec;
 use risc0_zkvm::{
     get_prover_server, sha::Digest, ExecutorEnv, ExecutorImpl, ProverOpts, VerifierContext,
 };
-use utils::{bench::benchmark, bench::Metrics, metadata::FIBONACCI_INPUTS};
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    metadata::FIBONACCI_INPUTS,
+    profile::{phase, Phase},
+};
 
 pub fn main() {
-    let csv_file = format!(
-        "../.outputs/benchmark/fib_risczero{}{}.csv",
-        if cfg!(feature = "cuda") { "-gpu" } else { "" },
-        ""
+    let gpu = if cfg!(feature = "cuda") { "-gpu" } else { "" };
+    benchmark(
+        benchmark_fib,
+        &FIBONACCI_INPUTS,
+        Target::from_args(
+            "fib",
+            &format!("risczero{}", gpu),
+            &format!("../.outputs/benchmark/fib_risczero{}.csv", gpu),
+        ),
     );
-    benchmark(benchmark_fib, &FIBONACCI_INPUTS, &csv_file);
 }
 
 fn benchmark_fib(input: u32) -> Metrics {
     let mut metrics = Metrics::new(input as usize);
     const ELF: &[u8] = risc0_benchmark_methods::FIBONACCI_ELF;
     let image_id: Digest = risc0_benchmark_methods::FIBONACCI_ID.into();
+    metrics.program_bytes = ELF.len();
+    // There are no proving keys, the image ID is all a verifier needs.
+    metrics.vk_bytes = image_id.as_bytes().len();
     let input = to_vec(&input).unwrap();
 
     let env = ExecutorEnv::builder().write_slice(&input).build().unwrap();
-    let mut exec = ExecutorImpl::from_elf(env, &ELF).unwrap();
+    let mut exec = phase(Phase::Load, || ExecutorImpl::from_elf(env, &ELF).unwrap());
     let start = Instant::now();
-    let session = exec.run().unwrap();
+    let session = phase(Phase::Execute, || exec.run().unwrap());
     metrics.exec_duration = start.elapsed();
     metrics.cycles = session.user_cycles;
 
-    let prover = get_prover_server(&ProverOpts::succinct()).unwrap();
+    let prover = phase(Phase::Init, || {
+        get_prover_server(&ProverOpts::succinct()).unwrap()
+    });
     let ctx = VerifierContext::default();
 
     let start = Instant::now();
-    let receipt = prover.prove_session(&ctx, &session).unwrap().receipt;
+    let receipt = phase(Phase::Prove, || {
+        prover.prove_session(&ctx, &session).unwrap().receipt
+    });
     metrics.proof_duration = start.elapsed();
     metrics.proof_bytes = receipt.inner.succinct().unwrap().seal_size();
 
     let start = Instant::now();
-    receipt.verify(image_id).unwrap();
+    phase(Phase::Verify, || receipt.verify(image_id).unwrap());
     metrics.verify_duration = start.elapsed();
 
     metrics
@@ -3,41 +3,58 @@ use risc0_zkvm::{
     get_prover_server, sha::Digest, ExecutorEnv, ExecutorImpl, ProverOpts, VerifierContext,
 };
 use std::time::Instant;
-use utils::{bench::benchmark, bench::Metrics, metadata::SHA2_INPUTS, sha2_input};
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    metadata::SHA2_INPUTS,
+    profile::{phase, Phase},
+    sha2_input,
+};
 
 pub fn main() {
-    let csv_file = format!(
-        "../.outputs/benchmark/sha2_risczero{}{}.csv",
-        if cfg!(feature = "cuda") { "-gpu" } else { "" },
-        ""
+    let gpu = if cfg!(feature = "cuda") { "-gpu" } else { "" };
+    benchmark(
+        benchmark_sha2,
+        &SHA2_INPUTS,
+        Target::from_args(
+            "sha2",
+            &format!("risczero{}", gpu),
+            &format!("../.outputs/benchmark/sha2_risczero{}.csv", gpu),
+        ),
     );
-    benchmark(benchmark_sha2, &SHA2_INPUTS, &csv_file);
 }
 
 fn benchmark_sha2(num_bytes: usize) -> Metrics {
     let mut metrics = Metrics::new(num_bytes);
     const ELF: &[u8] = risc0_benchmark_methods::BIG_SHA2_ELF;
     let image_id: Digest = risc0_benchmark_methods::BIG_SHA2_ID.into();
+    metrics.program_bytes = ELF.len();
+    // There are no proving keys, the image ID is all a verifier needs.
+    metrics.vk_bytes = image_id.as_bytes().len();
     let message = sha2_input(num_bytes);
     let input = to_vec(&message).unwrap();
 
     let env = ExecutorEnv::builder().write_slice(&input).build().unwrap();
-    let mut exec = ExecutorImpl::from_elf(env, &ELF).unwrap();
+    let mut exec = phase(Phase::Load, || ExecutorImpl::from_elf(env, &ELF).unwrap());
     let start = Instant::now();
-    let session = exec.run().unwrap();
+    let session = phase(Phase::Execute, || exec.run().unwrap());
     metrics.exec_duration = start.elapsed();
     metrics.cycles = session.user_cycles;
 
-    let prover = get_prover_server(&ProverOpts::succinct()).unwrap();
+    let prover = phase(Phase::Init, || {
+        get_prover_server(&ProverOpts::succinct()).unwrap()
+    });
     let ctx = VerifierContext::default();
 
     let start = Instant::now();
-    let receipt = prover.prove_session(&ctx, &session).unwrap().receipt;
+    let receipt = phase(Phase::Prove, || {
+        prover.prove_session(&ctx, &session).unwrap().receipt
+    });
     metrics.proof_duration = start.elapsed();
     metrics.proof_bytes = receipt.inner.succinct().unwrap().seal_size();
 
     let start = Instant::now();
-    receipt.verify(image_id).unwrap();
+    phase(Phase::Verify, || receipt.verify(image_id).unwrap());
     metrics.verify_duration = start.elapsed();
 
     metrics
@@ -0,0 +1,17 @@
+//! A simple program that takes an aggregate BLS signature and the public keys of its signers as
+//! input, and writes whether it is valid as an output.
+
+// These two lines are necessary for the program to properly compile.
+//
+// Under the hood, we wrap your main function with some extra code so that it behaves properly
+// inside the zkVM.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use guests::bls;
+
+fn main() {
+    let input = sp1_zkvm::io::read::<bls::BlsAggregateInput>();
+    let result = bls::verify_aggregate(&input);
+    sp1_zkvm::io::commit(&result);
+}
@@ -11,7 +11,7 @@ sp1_zkvm::entrypoint!(main);
 use guests::ecdsa;
 
 fn main() {
-    let input = sp1_zkvm::io::read::<ecdsa::EcdsaVerifyInput>();
-    let result = ecdsa::ecdsa_verify(input);
+    let input = sp1_zkvm::io::read::<Vec<ecdsa::EcdsaVerifyInput>>();
+    let result = ecdsa::ecdsa_verify_batch(input);
     sp1_zkvm::io::commit(&result);
 }
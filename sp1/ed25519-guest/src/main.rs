@@ -0,0 +1,17 @@
+//! A simple program that takes a batch of Ed25519 signatures as input, and writes whether they are
+//! all valid as an output.
+
+// These two lines are necessary for the program to properly compile.
+//
+// Under the hood, we wrap your main function with some extra code so that it behaves properly
+// inside the zkVM.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use guests::ed25519;
+
+fn main() {
+    let input = sp1_zkvm::io::read::<Vec<ed25519::Ed25519VerifyInput>>();
+    let result = ed25519::ed25519_verify_batch(input);
+    sp1_zkvm::io::commit(&result);
+}
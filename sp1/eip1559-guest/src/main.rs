@@ -0,0 +1,17 @@
+//! A simple program that takes a raw signed EIP-1559 transaction as input, and writes the address
+//! of its sender as an output.
+
+// These two lines are necessary for the program to properly compile.
+//
+// Under the hood, we wrap your main function with some extra code so that it behaves properly
+// inside the zkVM.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use guests::eip1559;
+
+pub fn main() {
+    let input: Vec<u8> = sp1_zkvm::io::read();
+    let sender = eip1559::recover_sender(&input).expect("invalid transaction");
+    sp1_zkvm::io::commit(&sender);
+}
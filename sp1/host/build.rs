@@ -3,6 +3,16 @@ use sp1_build::build_program_with_args;
 fn main() {
     build_program_with_args("../fibonacci-guest", Default::default());
     build_program_with_args("../sha2-guest", Default::default());
+    build_program_with_args("../keccak-guest", Default::default());
+    build_program_with_args("../merkle-guest", Default::default());
+    build_program_with_args("../eip1559-guest", Default::default());
+    build_program_with_args("../poseidon2-guest", Default::default());
     build_program_with_args("../ecdsa-guest", Default::default());
+    build_program_with_args("../ed25519-guest", Default::default());
+    build_program_with_args("../webauthn-guest", Default::default());
+    build_program_with_args("../jwt-guest", Default::default());
+    build_program_with_args("../bls-guest", Default::default());
+    build_program_with_args("../bls-precompile-guest", Default::default());
+    build_program_with_args("../mlp-guest", Default::default());
     build_program_with_args("../transfer-eth-guest", Default::default());
 }
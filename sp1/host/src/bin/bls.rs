@@ -0,0 +1,114 @@
+//! An end-to-end example of using the SP1 SDK to generate a proof of a program that can be executed
+//! or have a core proof generated.
+//!
+//! You can run this script using the following command:
+//! ```shell
+//! RUST_LOG=info cargo run --release -- --execute
+//! ```
+//! or
+//! ```shell
+//! RUST_LOG=info cargo run --release -- --prove
+//! ```
+//!
+
+use sp1_sdk::{include_elf, ProverClient, SP1Stdin};
+use std::time::Instant;
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    bls_input, bls_params, bls_precompile,
+    metadata::BLS_PAIRINGS,
+    params::Params,
+    profile::{phase, Phase},
+    size,
+    stats::InstructionStats,
+};
+
+/// The ELF (executable and linkable format) file for the Succinct RISC-V zkVM.
+pub const BLS_ELF: &[u8] = include_elf!("bls-guest");
+/// The same program, calling the BLS12-381 precompiles through the patched `bls12_381`.
+pub const BLS_PRECOMPILE_ELF: &[u8] = include_elf!("bls-precompile-guest");
+
+fn main() {
+    if std::env::var("SP1_PROVER").unwrap_or_default() == "cuda" {
+        benchmark(
+            bench_bls,
+            bls_params(&BLS_PAIRINGS),
+            Target::from_args("bls", "sp1-gpu", "../.outputs/benchmark/bls_sp1-gpu.csv"),
+        );
+    } else {
+        benchmark(
+            bench_bls,
+            bls_params(&BLS_PAIRINGS),
+            Target::from_args("bls", "sp1", "../.outputs/benchmark/bls_sp1.csv"),
+        );
+    }
+}
+
+fn bench_bls(params: Params) -> Metrics {
+    let mut metrics: Metrics = Metrics::new(params.clone());
+
+    let input = bls_input(&params);
+    let elf = if bls_precompile(&params) {
+        BLS_PRECOMPILE_ELF
+    } else {
+        BLS_ELF
+    };
+    // Setup the logger.
+    sp1_sdk::utils::setup_logger();
+    dotenv::dotenv().ok();
+
+    // Setup the prover client.
+    let client = phase(Phase::Init, || ProverClient::from_env());
+
+    // Setup the inputs.
+    let mut stdin = SP1Stdin::new();
+    stdin.write(&input);
+
+    // Execute the program
+    let start = Instant::now();
+    let (mut output, report) = phase(Phase::Execute, || {
+        client.execute(elf, &stdin).run().unwrap()
+    });
+    metrics.exec_duration = start.elapsed();
+    assert!(output.read::<bool>(), "invalid aggregate signature");
+    metrics.cycles = report.total_instruction_count() as u64;
+
+    InstructionStats::from_histograms(
+        report
+            .opcode_counts
+            .iter()
+            .map(|(opcode, count)| (opcode, *count)),
+        report
+            .syscall_counts
+            .iter()
+            .map(|(syscall, count)| (format!("{:?}", syscall), *count)),
+    )
+    .write("bls", "sp1", &params);
+
+    // Setup the program for proving.
+    let (pk, vk) = phase(Phase::Setup, || client.setup(elf));
+    metrics.pk_bytes = size(&pk);
+    metrics.vk_bytes = size(&vk);
+    metrics.program_bytes = elf.len();
+
+    let start = Instant::now();
+    // Generate the proof
+    let proof = phase(Phase::Prove, || {
+        client
+            .prove(&pk, &stdin)
+            .run()
+            .expect("failed to generate proof")
+    });
+    metrics.proof_duration = start.elapsed();
+    metrics.proof_bytes = size(&proof);
+
+    // Verify the proof.
+    let start = Instant::now();
+    phase(Phase::Verify, || {
+        client.verify(&proof, &vk).expect("failed to verify proof")
+    });
+    metrics.verify_duration = start.elapsed();
+
+    metrics
+}
@@ -13,7 +13,15 @@
 
 use sp1_sdk::{include_elf, ProverClient, SP1Stdin};
 use std::time::Instant;
-use utils::{bench::benchmark, bench::Metrics, ecdsa_input, metadata::ECDSA_INPUTS, size};
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    ecdsa_input,
+    metadata::ECDSA_INPUTS,
+    profile::{phase, Phase},
+    size,
+    stats::InstructionStats,
+};
 
 /// The ELF (executable and linkable format) file for the Succinct RISC-V zkVM.
 pub const ECDSA_ELF: &[u8] = include_elf!("ecdsa-guest");
@@ -23,13 +31,17 @@ fn main() {
         benchmark(
             bench_ecdsa,
             &ECDSA_INPUTS,
-            "../.outputs/benchmark/ecdsa_sp1-gpu.csv",
+            Target::from_args(
+                "ecdsa",
+                "sp1-gpu",
+                "../.outputs/benchmark/ecdsa_sp1-gpu.csv",
+            ),
         );
     } else {
         benchmark(
             bench_ecdsa,
             &ECDSA_INPUTS,
-            "../.outputs/benchmark/ecdsa_sp1.csv",
+            Target::from_args("ecdsa", "sp1", "../.outputs/benchmark/ecdsa_sp1.csv"),
         );
     }
 }
@@ -37,13 +49,13 @@ fn main() {
 fn bench_ecdsa(n: usize) -> Metrics {
     let mut metrics: Metrics = Metrics::new(n);
 
-    let input = ecdsa_input();
+    let input = ecdsa_input(n);
     // Setup the logger.
     sp1_sdk::utils::setup_logger();
     dotenv::dotenv().ok();
 
     // Setup the prover client.
-    let client = ProverClient::from_env();
+    let client = phase(Phase::Init, || ProverClient::from_env());
 
     // Setup the inputs.
     let mut stdin = SP1Stdin::new();
@@ -51,25 +63,46 @@ fn bench_ecdsa(n: usize) -> Metrics {
 
     // Execute the program
     let start = Instant::now();
-    let (_, report) = client.execute(ECDSA_ELF, &stdin).run().unwrap();
+    let (_, report) = phase(Phase::Execute, || {
+        client.execute(ECDSA_ELF, &stdin).run().unwrap()
+    });
     metrics.exec_duration = start.elapsed();
     metrics.cycles = report.total_instruction_count() as u64;
 
+    InstructionStats::from_histograms(
+        report
+            .opcode_counts
+            .iter()
+            .map(|(opcode, count)| (opcode, *count)),
+        report
+            .syscall_counts
+            .iter()
+            .map(|(syscall, count)| (format!("{:?}", syscall), *count)),
+    )
+    .write("ecdsa", "sp1", n);
+
     // Setup the program for proving.
-    let (pk, vk) = client.setup(ECDSA_ELF);
+    let (pk, vk) = phase(Phase::Setup, || client.setup(ECDSA_ELF));
+    metrics.pk_bytes = size(&pk);
+    metrics.vk_bytes = size(&vk);
+    metrics.program_bytes = ECDSA_ELF.len();
 
     let start = Instant::now();
     // Generate the proof
-    let proof = client
-        .prove(&pk, &stdin)
-        .run()
-        .expect("failed to generate proof");
+    let proof = phase(Phase::Prove, || {
+        client
+            .prove(&pk, &stdin)
+            .run()
+            .expect("failed to generate proof")
+    });
     metrics.proof_duration = start.elapsed();
     metrics.proof_bytes = size(&proof);
 
     // Verify the proof.
     let start = Instant::now();
-    client.verify(&proof, &vk).expect("failed to verify proof");
+    phase(Phase::Verify, || {
+        client.verify(&proof, &vk).expect("failed to verify proof")
+    });
     metrics.verify_duration = start.elapsed();
 
     metrics
@@ -0,0 +1,110 @@
+//! An end-to-end example of using the SP1 SDK to generate a proof of a program that can be executed
+//! or have a core proof generated.
+//!
+//! You can run this script using the following command:
+//! ```shell
+//! RUST_LOG=info cargo run --release -- --execute
+//! ```
+//! or
+//! ```shell
+//! RUST_LOG=info cargo run --release -- --prove
+//! ```
+//!
+
+use sp1_sdk::{include_elf, ProverClient, SP1Stdin};
+use std::time::Instant;
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    ed25519_input,
+    metadata::ED25519_INPUTS,
+    profile::{phase, Phase},
+    size,
+    stats::InstructionStats,
+};
+
+/// The ELF (executable and linkable format) file for the Succinct RISC-V zkVM.
+pub const ED25519_ELF: &[u8] = include_elf!("ed25519-guest");
+
+fn main() {
+    if std::env::var("SP1_PROVER").unwrap_or_default() == "cuda" {
+        benchmark(
+            bench_ed25519,
+            &ED25519_INPUTS,
+            Target::from_args(
+                "ed25519",
+                "sp1-gpu",
+                "../.outputs/benchmark/ed25519_sp1-gpu.csv",
+            ),
+        );
+    } else {
+        benchmark(
+            bench_ed25519,
+            &ED25519_INPUTS,
+            Target::from_args("ed25519", "sp1", "../.outputs/benchmark/ed25519_sp1.csv"),
+        );
+    }
+}
+
+fn bench_ed25519(n: usize) -> Metrics {
+    let mut metrics: Metrics = Metrics::new(n);
+
+    let input = ed25519_input(n);
+    // Setup the logger.
+    sp1_sdk::utils::setup_logger();
+    dotenv::dotenv().ok();
+
+    // Setup the prover client.
+    let client = phase(Phase::Init, || ProverClient::from_env());
+
+    // Setup the inputs.
+    let mut stdin = SP1Stdin::new();
+    stdin.write(&input);
+
+    // Execute the program
+    let start = Instant::now();
+    let (mut output, report) = phase(Phase::Execute, || {
+        client.execute(ED25519_ELF, &stdin).run().unwrap()
+    });
+    metrics.exec_duration = start.elapsed();
+    assert!(output.read::<bool>(), "invalid signatures");
+    metrics.cycles = report.total_instruction_count() as u64;
+
+    InstructionStats::from_histograms(
+        report
+            .opcode_counts
+            .iter()
+            .map(|(opcode, count)| (opcode, *count)),
+        report
+            .syscall_counts
+            .iter()
+            .map(|(syscall, count)| (format!("{:?}", syscall), *count)),
+    )
+    .write("ed25519", "sp1", n);
+
+    // Setup the program for proving.
+    let (pk, vk) = phase(Phase::Setup, || client.setup(ED25519_ELF));
+    metrics.pk_bytes = size(&pk);
+    metrics.vk_bytes = size(&vk);
+    metrics.program_bytes = ED25519_ELF.len();
+
+    let start = Instant::now();
+    // Generate the proof
+    let proof = phase(Phase::Prove, || {
+        client
+            .prove(&pk, &stdin)
+            .run()
+            .expect("failed to generate proof")
+    });
+    metrics.proof_duration = start.elapsed();
+    metrics.proof_bytes = size(&proof);
+
+    // Verify the proof.
+    let start = Instant::now();
+    phase(Phase::Verify, || {
+        client.verify(&proof, &vk).expect("failed to verify proof")
+    });
+    metrics.verify_duration = start.elapsed();
+
+    metrics
+}
@@ -13,7 +13,14 @@
 
 use sp1_sdk::{include_elf, ProverClient, SP1Stdin};
 use std::time::Instant;
-use utils::{bench::benchmark, bench::Metrics, metadata::FIBONACCI_INPUTS, size, write_json};
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    metadata::FIBONACCI_INPUTS,
+    profile::{phase, Phase},
+    size,
+    stats::InstructionStats,
+};
 
 /// The ELF (executable and linkable format) file for the Succinct RISC-V zkVM.
 pub const FIBONACCI_ELF: &[u8] = include_elf!("fibonacci-guest");
@@ -32,13 +39,17 @@ fn main() {
         benchmark(
             bench_fib,
             &[n],
-            format!("../.outputs/benchmark/fib_sp1-gpu-{}.csv", n).as_str(),
+            Target::new(
+                "fib",
+                "sp1-gpu",
+                &format!("../.outputs/benchmark/fib_sp1-gpu-{}.csv", n),
+            ),
         );
     } else {
         benchmark(
             bench_fib,
             &FIBONACCI_INPUTS,
-            "../.outputs/benchmark/fib_sp1.csv",
+            Target::from_args("fib", "sp1", "../.outputs/benchmark/fib_sp1.csv"),
         );
     }
 }
@@ -51,7 +62,7 @@ fn bench_fib(n: u32) -> Metrics {
     dotenv::dotenv().ok();
 
     // Setup the prover client.
-    let client = ProverClient::from_env();
+    let client = phase(Phase::Init, || ProverClient::from_env());
 
     // Setup the inputs.
     let mut stdin = SP1Stdin::new();
@@ -59,30 +70,46 @@ fn bench_fib(n: u32) -> Metrics {
 
     // Execute the program
     let start = Instant::now();
-    let (_output, report) = client.execute(FIBONACCI_ELF, &stdin).run().unwrap();
+    let (_output, report) = phase(Phase::Execute, || {
+        client.execute(FIBONACCI_ELF, &stdin).run().unwrap()
+    });
     metrics.exec_duration = start.elapsed();
     metrics.cycles = report.total_instruction_count();
 
-    write_json(
-        &report.opcode_counts,
-        &format!("../.outputs/traces/fib_sp1_{}.json", n),
-    );
+    InstructionStats::from_histograms(
+        report
+            .opcode_counts
+            .iter()
+            .map(|(opcode, count)| (opcode, *count)),
+        report
+            .syscall_counts
+            .iter()
+            .map(|(syscall, count)| (format!("{:?}", syscall), *count)),
+    )
+    .write("fib", "sp1", n as usize);
 
     // Setup the program for proving.
-    let (pk, vk) = client.setup(FIBONACCI_ELF);
+    let (pk, vk) = phase(Phase::Setup, || client.setup(FIBONACCI_ELF));
+    metrics.pk_bytes = size(&pk);
+    metrics.vk_bytes = size(&vk);
+    metrics.program_bytes = FIBONACCI_ELF.len();
 
     let start = Instant::now();
     // Generate the proof
-    let proof = client
-        .prove(&pk, &stdin)
-        .run()
-        .expect("failed to generate proof");
+    let proof = phase(Phase::Prove, || {
+        client
+            .prove(&pk, &stdin)
+            .run()
+            .expect("failed to generate proof")
+    });
     metrics.proof_duration = start.elapsed();
     metrics.proof_bytes = size(&proof);
 
     // Verify the proof.
     let start = Instant::now();
-    client.verify(&proof, &vk).expect("failed to verify proof");
+    phase(Phase::Verify, || {
+        client.verify(&proof, &vk).expect("failed to verify proof")
+    });
     metrics.verify_duration = start.elapsed();
 
     metrics
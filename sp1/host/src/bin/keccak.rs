@@ -0,0 +1,120 @@
+//! An end-to-end example of using the SP1 SDK to generate a proof of a program that can be executed
+//! or have a core proof generated.
+//!
+//! You can run this script using the following command:
+//! ```shell
+//! RUST_LOG=info cargo run --release -- --execute
+//! ```
+//! or
+//! ```shell
+//! RUST_LOG=info cargo run --release -- --prove
+//! ```
+//!
+
+use sp1_sdk::{include_elf, ProverClient, SP1Stdin};
+use std::time::Instant;
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    keccak_digest, keccak_input,
+    metadata::KECCAK_INPUTS,
+    profile::{phase, Phase},
+    size,
+    stats::InstructionStats,
+};
+
+/// The ELF (executable and linkable format) file for the Succinct RISC-V zkVM.
+pub const KECCAK_ELF: &[u8] = include_elf!("keccak-guest");
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if std::env::var("SP1_PROVER").unwrap_or_default() == "cuda" {
+        let n: usize = args
+            .iter()
+            .skip_while(|arg| *arg != "--n")
+            .nth(1)
+            .expect("Please provide a value for --n")
+            .parse()
+            .expect("Value for --n should be a valid u32");
+        benchmark(
+            bench_keccak,
+            &[n],
+            Target::new(
+                "keccak",
+                "sp1-gpu",
+                &format!("../.outputs/benchmark/keccak_sp1-gpu-{}.csv", n),
+            ),
+        );
+    } else {
+        benchmark(
+            bench_keccak,
+            &KECCAK_INPUTS,
+            Target::from_args("keccak", "sp1", "../.outputs/benchmark/keccak_sp1.csv"),
+        );
+    }
+}
+
+fn bench_keccak(num_bytes: usize) -> Metrics {
+    let mut metrics: Metrics = Metrics::new(num_bytes as usize);
+
+    // Setup the logger.
+    sp1_sdk::utils::setup_logger();
+    dotenv::dotenv().ok();
+
+    // Setup the prover client.
+    let client = phase(Phase::Init, || ProverClient::from_env());
+
+    // Setup the inputs.
+    let mut stdin = SP1Stdin::new();
+    let input = keccak_input(num_bytes);
+    stdin.write(&input);
+
+    // Execute the program
+    let start = Instant::now();
+    let (mut output, report) = phase(Phase::Execute, || {
+        client.execute(KECCAK_ELF, &stdin).run().unwrap()
+    });
+    metrics.exec_duration = start.elapsed();
+    assert_eq!(output.read::<[u8; 32]>(), keccak_digest(&input));
+    metrics.cycles = report.total_instruction_count() as u64;
+
+    InstructionStats::from_histograms(
+        report
+            .opcode_counts
+            .iter()
+            .map(|(opcode, count)| (opcode, *count)),
+        report
+            .syscall_counts
+            .iter()
+            .map(|(syscall, count)| (format!("{:?}", syscall), *count)),
+    )
+    .write("keccak", "sp1", num_bytes);
+
+    // Setup the program for proving.
+    let (pk, vk) = phase(Phase::Setup, || client.setup(KECCAK_ELF));
+    metrics.pk_bytes = size(&pk);
+    metrics.vk_bytes = size(&vk);
+    metrics.program_bytes = KECCAK_ELF.len();
+
+    let start = Instant::now();
+    // Generate the proof
+    let proof = phase(Phase::Prove, || {
+        client
+            .prove(&pk, &stdin)
+            .run()
+            .expect("failed to generate proof")
+    });
+    metrics.proof_duration = start.elapsed();
+    metrics.proof_bytes = size(&proof);
+
+    println!("Successfully generated proof!");
+
+    // Verify the proof.
+    let start = Instant::now();
+    phase(Phase::Verify, || {
+        client.verify(&proof, &vk).expect("failed to verify proof")
+    });
+    metrics.verify_duration = start.elapsed();
+
+    metrics
+}
@@ -13,7 +13,14 @@
 
 use sp1_sdk::{include_elf, ProverClient, SP1Stdin};
 use std::time::Instant;
-use utils::{bench::benchmark, bench::Metrics, metadata::SHA2_INPUTS, sha2_input, size};
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    metadata::SHA2_INPUTS,
+    profile::{phase, Phase},
+    sha2_input, size,
+    stats::InstructionStats,
+};
 
 /// The ELF (executable and linkable format) file for the Succinct RISC-V zkVM.
 pub const SHA2_ELF: &[u8] = include_elf!("sha2-guest");
@@ -31,13 +38,17 @@ fn main() {
         benchmark(
             bench_sha2,
             &[n],
-            format!("../.outputs/benchmark/sha2_sp1-gpu-{}.csv", n).as_str(),
+            Target::new(
+                "sha2",
+                "sp1-gpu",
+                &format!("../.outputs/benchmark/sha2_sp1-gpu-{}.csv", n),
+            ),
         );
     } else {
         benchmark(
             bench_sha2,
             &SHA2_INPUTS,
-            "../.outputs/benchmark/sha2_sp1.csv",
+            Target::from_args("sha2", "sp1", "../.outputs/benchmark/sha2_sp1.csv"),
         );
     }
 }
@@ -50,7 +61,7 @@ fn bench_sha2(num_bytes: usize) -> Metrics {
     dotenv::dotenv().ok();
 
     // Setup the prover client.
-    let client = ProverClient::from_env();
+    let client = phase(Phase::Init, || ProverClient::from_env());
 
     // Setup the inputs.
     let mut stdin = SP1Stdin::new();
@@ -59,19 +70,38 @@ fn bench_sha2(num_bytes: usize) -> Metrics {
 
     // Execute the program
     let start = Instant::now();
-    let (_, report) = client.execute(SHA2_ELF, &stdin).run().unwrap();
+    let (_, report) = phase(Phase::Execute, || {
+        client.execute(SHA2_ELF, &stdin).run().unwrap()
+    });
     metrics.exec_duration = start.elapsed();
     metrics.cycles = report.total_instruction_count() as u64;
 
+    InstructionStats::from_histograms(
+        report
+            .opcode_counts
+            .iter()
+            .map(|(opcode, count)| (opcode, *count)),
+        report
+            .syscall_counts
+            .iter()
+            .map(|(syscall, count)| (format!("{:?}", syscall), *count)),
+    )
+    .write("sha2", "sp1", num_bytes);
+
     // Setup the program for proving.
-    let (pk, vk) = client.setup(SHA2_ELF);
+    let (pk, vk) = phase(Phase::Setup, || client.setup(SHA2_ELF));
+    metrics.pk_bytes = size(&pk);
+    metrics.vk_bytes = size(&vk);
+    metrics.program_bytes = SHA2_ELF.len();
 
     let start = Instant::now();
     // Generate the proof
-    let proof = client
-        .prove(&pk, &stdin)
-        .run()
-        .expect("failed to generate proof");
+    let proof = phase(Phase::Prove, || {
+        client
+            .prove(&pk, &stdin)
+            .run()
+            .expect("failed to generate proof")
+    });
     metrics.proof_duration = start.elapsed();
     metrics.proof_bytes = size(&proof);
 
@@ -79,7 +109,9 @@ fn bench_sha2(num_bytes: usize) -> Metrics {
 
     // Verify the proof.
     let start = Instant::now();
-    client.verify(&proof, &vk).expect("failed to verify proof");
+    phase(Phase::Verify, || {
+        client.verify(&proof, &vk).expect("failed to verify proof")
+    });
     metrics.verify_duration = start.elapsed();
 
     metrics
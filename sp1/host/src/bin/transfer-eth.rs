@@ -13,7 +13,14 @@
 
 use sp1_sdk::{include_elf, ProverClient, SP1Stdin};
 use std::time::Instant;
-use utils::{bench::benchmark, bench::Metrics, metadata::ETHTRANSFER_INPUTS, size};
+use utils::{
+    bench::Metrics,
+    bench::{benchmark, Target},
+    metadata::ETHTRANSFER_INPUTS,
+    profile::{phase, Phase},
+    size,
+    stats::InstructionStats,
+};
 
 /// The ELF (executable and linkable format) file for the Succinct RISC-V zkVM.
 pub const EVM_ELF: &[u8] = include_elf!("transfer-eth-guest");
@@ -31,13 +38,21 @@ fn main() {
         benchmark(
             bench_evm,
             &[n],
-            format!("../.outputs/benchmark/ethtransfer_sp1-gpu-{}.csv", n).as_str(),
+            Target::new(
+                "ethtransfer",
+                "sp1-gpu",
+                &format!("../.outputs/benchmark/ethtransfer_sp1-gpu-{}.csv", n),
+            ),
         );
     } else {
         benchmark(
             bench_evm,
             &ETHTRANSFER_INPUTS,
-            "../.outputs/benchmark/ethtransfer_sp1.csv",
+            Target::from_args(
+                "ethtransfer",
+                "sp1",
+                "../.outputs/benchmark/ethtransfer_sp1.csv",
+            ),
         );
     }
 }
@@ -50,7 +65,7 @@ fn bench_evm(num_txs: usize) -> Metrics {
     dotenv::dotenv().ok();
 
     // Setup the prover client.
-    let client = ProverClient::from_env();
+    let client = phase(Phase::Init, || ProverClient::from_env());
 
     // Setup the inputs.
     let mut stdin = SP1Stdin::new();
@@ -58,25 +73,46 @@ fn bench_evm(num_txs: usize) -> Metrics {
 
     // Execute the program
     let start = Instant::now();
-    let (_, report) = client.execute(EVM_ELF, &stdin).run().unwrap();
+    let (_, report) = phase(Phase::Execute, || {
+        client.execute(EVM_ELF, &stdin).run().unwrap()
+    });
     metrics.exec_duration = start.elapsed();
     metrics.cycles = report.total_instruction_count() as u64;
 
+    InstructionStats::from_histograms(
+        report
+            .opcode_counts
+            .iter()
+            .map(|(opcode, count)| (opcode, *count)),
+        report
+            .syscall_counts
+            .iter()
+            .map(|(syscall, count)| (format!("{:?}", syscall), *count)),
+    )
+    .write("ethtransfer", "sp1", num_txs);
+
     // Setup the program for proving.
-    let (pk, vk) = client.setup(EVM_ELF);
+    let (pk, vk) = phase(Phase::Setup, || client.setup(EVM_ELF));
+    metrics.pk_bytes = size(&pk);
+    metrics.vk_bytes = size(&vk);
+    metrics.program_bytes = EVM_ELF.len();
 
     let start = Instant::now();
     // Generate the proof
-    let proof = client
-        .prove(&pk, &stdin)
-        .run()
-        .expect("failed to generate proof");
+    let proof = phase(Phase::Prove, || {
+        client
+            .prove(&pk, &stdin)
+            .run()
+            .expect("failed to generate proof")
+    });
     metrics.proof_duration = start.elapsed();
     metrics.proof_bytes = size(&proof);
 
     // Verify the proof.
     let start = Instant::now();
-    client.verify(&proof, &vk).expect("failed to verify proof");
+    phase(Phase::Verify, || {
+        client.verify(&proof, &vk).expect("failed to verify proof")
+    });
     metrics.verify_duration = start.elapsed();
 
     metrics
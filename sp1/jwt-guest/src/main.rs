@@ -0,0 +1,17 @@
+//! A simple program that takes an RS256 ID token and the public key of its issuer as input, and
+//! writes the subject of the token as an output.
+
+// These two lines are necessary for the program to properly compile.
+//
+// Under the hood, we wrap your main function with some extra code so that it behaves properly
+// inside the zkVM.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use guests::jwt;
+
+pub fn main() {
+    let input: jwt::JwtInput = sp1_zkvm::io::read();
+    let subject = jwt::verify_token(&input).expect("invalid token");
+    sp1_zkvm::io::commit(&subject);
+}
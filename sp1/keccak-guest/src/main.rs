@@ -0,0 +1,16 @@
+//! A simple program that takes a message as input, and writes its Keccak-256 digest as an output.
+
+// These two lines are necessary for the program to properly compile.
+//
+// Under the hood, we wrap your main function with some extra code so that it behaves properly
+// inside the zkVM.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use guests::keccak;
+
+pub fn main() {
+    let input: Vec<u8> = sp1_zkvm::io::read();
+    let result = keccak::keccak(&input);
+    sp1_zkvm::io::commit(&result);
+}
@@ -0,0 +1,17 @@
+//! A simple program that takes a batch of Merkle inclusion proofs as input, and writes whether
+//! they are all valid as an output.
+
+// These two lines are necessary for the program to properly compile.
+//
+// Under the hood, we wrap your main function with some extra code so that it behaves properly
+// inside the zkVM.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use guests::merkle;
+
+pub fn main() {
+    let input = sp1_zkvm::io::read::<merkle::MerkleInput>();
+    let result = merkle::verify_batch(&input);
+    sp1_zkvm::io::commit(&result);
+}
@@ -0,0 +1,17 @@
+//! A simple program that takes the width of a quantized MLP and an input vector as input, and
+//! writes the logits of the model as an output.
+
+// These two lines are necessary for the program to properly compile.
+//
+// Under the hood, we wrap your main function with some extra code so that it behaves properly
+// inside the zkVM.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use guests::mlp;
+
+fn main() {
+    let input = sp1_zkvm::io::read::<mlp::MlpInput>();
+    let logits = mlp::infer(&input);
+    sp1_zkvm::io::commit(&logits);
+}
@@ -0,0 +1,17 @@
+//! A simple program that takes a number `n` as input, and writes the result of `n` Poseidon2
+//! permutations as an output.
+
+// These two lines are necessary for the program to properly compile.
+//
+// Under the hood, we wrap your main function with some extra code so that it behaves properly
+// inside the zkVM.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use guests::poseidon2;
+
+pub fn main() {
+    let n = sp1_zkvm::io::read::<u32>();
+    let result = poseidon2::hash_chain(n);
+    sp1_zkvm::io::commit(&result);
+}
@@ -1,17 +1,36 @@
 use human_repr::{HumanCount, HumanDuration};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use serde_with::{serde_as, DurationNanoSeconds};
 use std::{
-    fmt::Display,
+    borrow::Borrow,
+    collections::{BTreeSet, HashMap},
+    fmt::{Debug, Display},
+    fs::{File, OpenOptions},
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
     sync::{
         atomic::{AtomicBool, AtomicUsize, Ordering},
         Arc,
     },
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tabled::{settings::Style, Table, Tabled};
 
+use crate::cold_start::{self, ColdStartMetrics};
+use crate::heap::{self, measure_heap, HeapStats};
+use crate::openmetrics::OpenMetricsExporter;
+use crate::params::{self, ParamSet, ParamValue, Params};
+use crate::preflight::{self, PreflightConfig};
+use crate::profile::{self, Phase};
+use crate::results::RunRecorder;
+use crate::rusage::{self, measure_counters, OsCounters};
+use crate::suite::{OUTPUT_DIR_ENV, REPETITIONS_ENV, RESUME_ENV, SIZES_ENV};
+use crate::sysfs::Sysfs;
+use crate::thermal::{ThermalMonitor, ThermalStats};
+use crate::timeline::{self, MemoryTimeline, RssSample, RunTimeline};
+
 fn get_current_memory_usage() -> Result<usize, std::io::Error> {
     unsafe {
         let mut out: libc::rusage = std::mem::zeroed();
@@ -31,33 +50,89 @@ fn get_current_memory_usage() -> Result<usize, std::io::Error> {
     }
 }
 
+/// Interval at which the memory monitor samples the memory usage.
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(10);
+
+/// The temperature and the CPU frequency are sampled every `THERMAL_SAMPLE_EVERY` memory samples,
+/// as reading them for every core is slower.
+const THERMAL_SAMPLE_EVERY: u64 = 10;
+
 pub fn measure_peak_memory<R, F: FnOnce() -> R>(func: F) -> (R, usize) {
+    let (result, monitored) = monitor_memory(func, false);
+    (result, monitored.peak_memory)
+}
+
+/// What the monitor thread observed during a run.
+struct Monitored {
+    peak_memory: usize,
+    timeline: Option<RunTimeline>,
+    thermal: ThermalStats,
+}
+
+/// Runs `func` while sampling the memory usage, the temperature and the CPU frequency. With
+/// `keep_timeline`, the RSS time series and the phases of the run are kept as well.
+fn monitor_memory<R, F: FnOnce() -> R>(func: F, keep_timeline: bool) -> (R, Monitored) {
     let peak = Arc::new(AtomicUsize::new(0));
     let stop = Arc::new(AtomicBool::new(false));
+    let origin = Instant::now();
+    if keep_timeline {
+        timeline::start(origin);
+    }
+    let mut thermal = ThermalMonitor::start(Sysfs::from_env());
 
     let peak_clone = Arc::clone(&peak);
     let stop_clone = Arc::clone(&stop);
     let monitor = thread::spawn(move || {
-        while !stop_clone.load(Ordering::Relaxed) {
+        let mut excluded = rusage::ExcludedThread::new();
+        let mut samples = Vec::new();
+        for i in 0.. {
+            if stop_clone.load(Ordering::Relaxed) {
+                break;
+            }
             if let Ok(mem) = get_current_memory_usage() {
                 peak_clone.fetch_max(mem, Ordering::Relaxed);
             }
-            thread::sleep(Duration::from_millis(10));
+            if keep_timeline {
+                if let Some(rss) = timeline::current_rss() {
+                    samples.push(RssSample {
+                        t_ms: timeline::elapsed_ms(origin),
+                        rss,
+                    });
+                }
+            }
+            if i % THERMAL_SAMPLE_EVERY == 0 {
+                thermal.sample();
+            }
+            excluded.update();
+            thread::sleep(SAMPLE_INTERVAL);
         }
+        excluded.update();
+        (samples, thermal.finish())
     });
 
     let result = func();
 
     stop.store(true, Ordering::Relaxed);
-    monitor.join().unwrap();
+    let (samples, thermal) = monitor.join().unwrap();
+    let monitored = Monitored {
+        peak_memory: peak.load(Ordering::Relaxed),
+        timeline: keep_timeline.then(|| RunTimeline {
+            samples,
+            phases: timeline::finish(),
+        }),
+        thermal,
+    };
 
-    (result, peak.load(Ordering::Relaxed))
+    (result, monitored)
 }
 
 #[serde_as]
 #[derive(Serialize, Tabled)]
 pub struct Metrics {
-    pub size: usize,
+    /// Parameters of the run, written as the first columns of the results.
+    #[serde(skip)]
+    #[tabled(display_with = "display_params")]
+    pub params: Params,
     #[serde_as(as = "DurationNanoSeconds")]
     #[tabled(display_with = "display_duration")]
     pub exec_duration: Duration,
@@ -71,64 +146,609 @@ pub struct Metrics {
     pub cycles: u64,
     #[tabled(display_with = "display_bytes")]
     pub proof_bytes: usize,
+    /// Serialized size of what an app has to ship or download to prove and verify: the proving
+    /// and verifying keys, the committed program (e.g. OpenVM's committed exe), and the guest ELF
+    /// or program artifact. Zero when a stack has no such artifact or does not expose it.
+    #[tabled(display_with = "display_bytes")]
+    pub pk_bytes: usize,
+    #[tabled(display_with = "display_bytes")]
+    pub vk_bytes: usize,
+    #[tabled(display_with = "display_bytes")]
+    pub committed_program_bytes: usize,
+    #[tabled(display_with = "display_bytes")]
+    pub program_bytes: usize,
     #[tabled(display_with = "display_bytes")]
     pub peak_memory: usize,
+    /// Heap usage and OS counters of the whole run.
+    #[serde(flatten)]
+    #[tabled(rename = "heap_peak", display_with = "display_heap_peak")]
+    pub totals: PhaseStats,
+    /// Heap usage and OS counters of each phase, written as `<phase>_<stat>` columns, e.g.
+    /// `prove_heap_peak`.
+    #[serde(flatten)]
+    #[tabled(skip)]
+    pub phases: Phases,
+    /// Whether pages were swapped in or out, by any process, during the run.
+    #[tabled(skip)]
+    pub swap_detected: bool,
+    /// Maximum temperature in degrees Celsius, minimum and average core frequency in MHz, and
+    /// whether the CPUs were throttled during the run (see [`crate::thermal`]).
+    #[tabled(skip)]
+    pub max_temperature: f64,
+    #[tabled(skip)]
+    pub min_frequency: u64,
+    #[tabled(skip)]
+    pub avg_frequency: u64,
+    #[tabled(skip)]
+    pub throttled: bool,
+    pub status: Status,
+}
+
+/// Heap usage and OS counters of a run or of one of its phases. Repeated phases, e.g. one proof
+/// per shard, are accumulated.
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+pub struct PhaseStats {
+    /// Only measured when the counting allocator is installed (see [`crate::heap`]).
+    pub heap_peak: usize,
+    pub heap_allocated: u64,
+    pub heap_allocations: u64,
+    /// Page faults, context switches and storage I/O (see [`crate::rusage`]).
+    pub major_faults: u64,
+    pub minor_faults: u64,
+    pub voluntary_switches: u64,
+    pub involuntary_switches: u64,
+    pub read_bytes: u64,
+    pub write_bytes: u64,
+}
+
+impl PhaseStats {
+    fn add_heap(&mut self, stats: HeapStats) {
+        self.heap_peak = self.heap_peak.max(stats.peak_bytes);
+        self.heap_allocated += stats.allocated_bytes;
+        self.heap_allocations += stats.allocations;
+    }
+
+    fn add_os_counters(&mut self, counters: OsCounters) {
+        self.major_faults += counters.major_faults;
+        self.minor_faults += counters.minor_faults;
+        self.voluntary_switches += counters.voluntary_switches;
+        self.involuntary_switches += counters.involuntary_switches;
+        self.read_bytes += counters.read_bytes;
+        self.write_bytes += counters.write_bytes;
+    }
+}
+
+/// [`PhaseStats`] of each phase of a run, keyed by the name of the phase.
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+pub struct Phases {
+    pub init: PhaseStats,
+    pub load: PhaseStats,
+    pub execute: PhaseStats,
+    pub setup: PhaseStats,
+    pub prove: PhaseStats,
+    pub verify: PhaseStats,
+}
+
+impl Phases {
+    pub fn get(&self, phase: Phase) -> &PhaseStats {
+        match phase {
+            Phase::Init => &self.init,
+            Phase::Load => &self.load,
+            Phase::Execute => &self.execute,
+            Phase::Setup => &self.setup,
+            Phase::Prove => &self.prove,
+            Phase::Verify => &self.verify,
+        }
+    }
+
+    fn get_mut(&mut self, phase: Phase) -> &mut PhaseStats {
+        match phase {
+            Phase::Init => &mut self.init,
+            Phase::Load => &mut self.load,
+            Phase::Execute => &mut self.execute,
+            Phase::Setup => &mut self.setup,
+            Phase::Prove => &mut self.prove,
+            Phase::Verify => &mut self.verify,
+        }
+    }
+}
+
+/// Outcome of a single benchmark run.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    #[default]
+    Ok,
+    Failed,
+    /// Succeeded while one of its phases was profiled. The sampling skews its timings, so it is
+    /// only shown, and not written to the results, the database or the OpenMetrics export.
+    Profiled,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Failed => write!(f, "failed"),
+            Status::Profiled => write!(f, "profiled"),
+        }
+    }
+}
+
+pub(crate) fn display_params(params: &Params) -> String {
+    params.to_string()
 }
 
 fn display_bytes(bytes: &usize) -> String {
     bytes.human_count_bytes().to_string()
 }
 
+fn display_heap_peak(stats: &PhaseStats) -> String {
+    display_bytes(&stats.heap_peak)
+}
+
 fn display_cycles(cycles: &u64) -> String {
     cycles.human_count_bare().to_string()
 }
 
-fn display_duration(duration: &Duration) -> String {
+pub(crate) fn display_duration(duration: &Duration) -> String {
     duration.human_duration().to_string()
 }
 
 impl Metrics {
-    pub fn new(size: usize) -> Self {
+    pub fn new(params: impl ParamSet) -> Self {
         Metrics {
-            size,
+            params: params.to_params(),
             exec_duration: Duration::default(),
             proof_duration: Duration::default(),
             verify_duration: Duration::default(),
             cycles: 0,
             proof_bytes: 0,
+            pk_bytes: 0,
+            vk_bytes: 0,
+            committed_program_bytes: 0,
+            program_bytes: 0,
             peak_memory: 0,
+            totals: PhaseStats::default(),
+            phases: Phases::default(),
+            swap_detected: false,
+            max_temperature: 0.0,
+            min_frequency: 0,
+            avg_frequency: 0,
+            throttled: false,
+            status: Status::Ok,
+        }
+    }
+
+    /// Returns the stats of the whole run, or of one of its phases.
+    fn stats_mut(&mut self, phase: Option<Phase>) -> &mut PhaseStats {
+        match phase {
+            None => &mut self.totals,
+            Some(phase) => self.phases.get_mut(phase),
+        }
+    }
+}
+
+/// What a host benchmarks, recorded with each of its runs, the file its results are written to,
+/// and the parameter sets given on its command line to replace its inputs.
+pub struct Target {
+    pub workload: String,
+    pub zkvm: String,
+    pub file: String,
+    pub overrides: Option<Vec<Params>>,
+}
+
+impl Target {
+    /// For hosts parsing their own command line, which can set [`Target::overrides`] themselves.
+    pub fn new(workload: &str, zkvm: &str, file: &str) -> Self {
+        Target {
+            workload: workload.to_string(),
+            zkvm: zkvm.to_string(),
+            file: file.to_string(),
+            overrides: None,
+        }
+    }
+
+    /// For hosts without a command line of their own: the `--param` arguments of the process
+    /// replace the inputs, see [`params::from_args`].
+    pub fn from_args(workload: &str, zkvm: &str, file: &str) -> Self {
+        let args: Vec<String> = std::env::args().collect();
+        Target {
+            overrides: params::from_args(&args),
+            ..Self::new(workload, zkvm, file)
         }
     }
 }
 
-pub fn benchmark<T: Display + Clone, F>(func: F, inputs: &[T], file: &str)
+/// Benchmarks `func` over each parameter set of `inputs` and writes the results to the file of
+/// `target`, with a column per parameter.
+///
+/// Each row is flushed as soon as its run finishes, and a panicking run is recorded with a
+/// `failed` status instead of aborting the sweep. In resume mode, parameter sets that already
+/// have enough `ok` rows in `file` are skipped and new rows are appended. The runs profiled with
+/// `--profile` are not recorded, see [`Status::Profiled`].
+///
+/// The inputs can be replaced by the overrides of `target`, e.g. the cartesian product of
+/// `--param <name>=<values>` arguments (see [`Target::from_args`]), where the parameters left out
+/// take their value in the first input. They, the number of repetitions and the output directory
+/// can also be overridden through the environment variables set by the suite orchestrator (see
+/// [`crate::suite`]). The machine is checked beforehand, see [`crate::preflight`]. In cold-start
+/// mode, only the time to the first proof of the first input is measured, see
+/// [`crate::cold_start`].
+pub fn benchmark<I, T, F>(func: F, inputs: I, target: Target)
 where
+    I: IntoIterator,
+    I::Item: Borrow<T>,
+    T: ParamSet + Clone,
     F: Fn(T) -> Metrics,
 {
+    let process_startup = cold_start::process_uptime().unwrap_or_default();
+    let inputs: Vec<T> = inputs
+        .into_iter()
+        .map(|input| input.borrow().clone())
+        .collect();
+    let inputs = inputs_override(target.overrides, &inputs)
+        .unwrap_or_else(|err| panic!("{}", err))
+        .unwrap_or(inputs);
+    let repetitions = std::env::var(REPETITIONS_ENV)
+        .map(|value| value.parse().expect("Invalid number of repetitions"))
+        .unwrap_or(1);
+    let path = output_path(&target.file);
+    let Target { workload, zkvm, .. } = target;
+
+    if cold_start::enabled() {
+        preflight::run(&PreflightConfig::from_env());
+        let input = inputs.first().expect("No input to benchmark");
+        return benchmark_cold_start(func, input, &path, process_startup);
+    }
+
+    let params = inputs.first().map(ParamSet::to_params).unwrap_or_default();
+    let header = csv_record(&params, &Metrics::new(params.clone())).0;
+    let resume = std::env::var(RESUME_ENV).is_ok();
+    let mut out = ResultsWriter::open(&path, header, resume);
+    let completed = if resume {
+        let names: Vec<String> = params.names().map(str::to_string).collect();
+        completed_runs(&path, &names)
+    } else {
+        HashMap::new()
+    };
+
+    let preflight = preflight::run(&PreflightConfig::from_env());
+    let recorder = RunRecorder::start(&zkvm, &workload);
+    let preflight_path = path.with_file_name(format!("{}_{}_preflight.json", workload, zkvm));
+    if let Err(err) = preflight.write(&preflight_path) {
+        eprintln!("Could not write preflight: {}", err);
+    }
+    if let Some(recorder) = &recorder {
+        recorder.artifact_content("preflight", &preflight_path, preflight.to_json().as_bytes());
+    }
+    let mut exporter = OpenMetricsExporter::from_env(&zkvm, &workload);
+
     let mut results = Vec::new();
-    for input in inputs {
-        let (mut metrics, peak_memory) = measure_peak_memory(|| func(input.clone()));
-        metrics.peak_memory = peak_memory;
-        results.push(metrics);
+    for input in &inputs {
+        let params = input.to_params();
+        let done = completed.get(&params.to_string()).copied().unwrap_or(0);
+        if done >= repetitions {
+            println!("Skipping {}: already benchmarked", params);
+            continue;
+        }
+
+        let mut memory_timeline = timeline::enabled().then(|| MemoryTimeline {
+            workload: workload.clone(),
+            zkvm: zkvm.clone(),
+            size: params.to_string(),
+            interval_ms: SAMPLE_INTERVAL.as_millis(),
+            runs: Vec::new(),
+        });
+        for repetition in done..repetitions {
+            profile::set_run_name(&format!("{}_{}_{}_{}", workload, zkvm, params, repetition));
+            heap::take_phase_stats();
+            rusage::take_phase_stats();
+            let (((result, counters), heap_stats), monitored) = monitor_memory(
+                || {
+                    measure_heap(|| {
+                        measure_counters(|| {
+                            panic::catch_unwind(AssertUnwindSafe(|| func(input.clone())))
+                        })
+                    })
+                },
+                memory_timeline.is_some(),
+            );
+            let mut metrics = result.unwrap_or_else(|_| {
+                eprintln!("Benchmark failed for {}", params);
+                let mut metrics = Metrics::new(params.clone());
+                metrics.status = Status::Failed;
+                metrics
+            });
+            metrics.peak_memory = monitored.peak_memory;
+            metrics.max_temperature = monitored.thermal.max_temperature;
+            metrics.min_frequency = monitored.thermal.min_frequency;
+            metrics.avg_frequency = monitored.thermal.avg_frequency;
+            metrics.throttled = monitored.thermal.throttled;
+            if metrics.throttled {
+                eprintln!(
+                    "Warning: CPU throttling detected while benchmarking {}, results may be skewed",
+                    params
+                );
+            }
+            if let Some(stats) = heap_stats {
+                metrics.stats_mut(None).add_heap(stats);
+            }
+            for (phase, stats) in heap::take_phase_stats() {
+                metrics.stats_mut(Some(phase)).add_heap(stats);
+            }
+            metrics.stats_mut(None).add_os_counters(counters);
+            for (phase, counters) in rusage::take_phase_stats() {
+                metrics.stats_mut(Some(phase)).add_os_counters(counters);
+            }
+            metrics.swap_detected = counters.swapped_pages > 0;
+            if metrics.swap_detected {
+                eprintln!(
+                    "Warning: swap activity detected while benchmarking {}, results may be skewed",
+                    params
+                );
+            }
+
+            if profile::enabled() && metrics.status == Status::Ok {
+                metrics.status = Status::Profiled;
+            }
+
+            if metrics.status != Status::Profiled {
+                out.write(&metrics.params, &metrics);
+                if let Some(recorder) = &recorder {
+                    recorder.record(&metrics);
+                }
+                if let Some(exporter) = &mut exporter {
+                    if let Err(err) = exporter.record(&metrics) {
+                        eprintln!("Could not export OpenMetrics: {}", err);
+                    }
+                }
+            }
+            if let (Some(memory_timeline), Some(run_timeline)) =
+                (&mut memory_timeline, monitored.timeline)
+            {
+                memory_timeline.runs.push(run_timeline);
+                let timeline_path =
+                    path.with_file_name(format!("{}_{}_{}_memory.json", workload, zkvm, params));
+                if let Err(err) = memory_timeline.write(&timeline_path) {
+                    eprintln!("Could not write memory timeline: {}", err);
+                }
+            }
+            results.push(metrics);
+        }
     }
 
-    write_csv(file, &results);
+    if let Some(recorder) = &recorder {
+        recorder.artifact("csv", &path);
+    }
+    print_table(&results);
 }
 
-pub fn write_csv(out_path: &str, results: &[Metrics]) {
-    let mut out = csv::WriterBuilder::new().from_path(out_path).unwrap();
+/// Measures the time to the first proof of `input`, see [`crate::cold_start`].
+fn benchmark_cold_start<T, F>(func: F, input: &T, path: &Path, process_startup: Duration)
+where
+    T: ParamSet + Clone,
+    F: Fn(T) -> Metrics,
+{
+    let params = input.to_params();
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let cold_path = path.with_file_name(format!("{}_cold_start.csv", stem));
+    let empty = ColdStartMetrics::from_phases(params.clone(), Duration::ZERO, &[], Duration::ZERO);
+    let mut out = ResultsWriter::open(&cold_path, csv_record(&params, &empty).0, true);
+
+    let start = Instant::now();
+    let (result, monitored) = monitor_memory(
+        || panic::catch_unwind(AssertUnwindSafe(|| func(input.clone()))),
+        true,
+    );
+    let run_duration = start.elapsed();
+
+    let phases = monitored.timeline.map(|run| run.phases).unwrap_or_default();
+    let mut metrics =
+        ColdStartMetrics::from_phases(params.clone(), process_startup, &phases, run_duration);
+    if result.is_err() {
+        eprintln!("Benchmark failed for {}", params);
+        metrics.status = Status::Failed;
+    } else if profile::enabled() {
+        metrics.status = Status::Profiled;
+    }
+
+    if metrics.status != Status::Profiled {
+        out.write(&params, &metrics);
+    }
+
+    let mut table = Table::new([metrics]);
+    table.with(Style::modern());
+    println!("{table}");
+}
+
+/// Counts the `ok` rows per parameter set, keyed by its display, of a results file with the
+/// parameter columns `names`, as checked by [`ResultsWriter::open`].
+fn completed_runs(path: &Path, names: &[String]) -> HashMap<String, usize> {
+    let mut reader = csv::Reader::from_path(path).expect("Could not read results file");
+    let headers = reader
+        .headers()
+        .expect("Could not read results file")
+        .clone();
+    let column = |name: &str| {
+        headers
+            .iter()
+            .position(|header| header == name)
+            .unwrap_or_else(|| panic!("Missing column {} in {}", name, path.display()))
+    };
+    let columns: Vec<usize> = names.iter().map(|name| column(name)).collect();
+    let status = column("status");
+
+    let mut completed = HashMap::new();
+    for record in reader.records().map_while(Result::ok) {
+        if record.get(status) == Some("ok") {
+            let params = names
+                .iter()
+                .zip(&columns)
+                .fold(Params::new(), |params, (name, &i)| {
+                    params.with(name, record[i].parse::<ParamValue>().unwrap())
+                });
+            *completed.entry(params.to_string()).or_default() += 1;
+        }
+    }
+    completed
+}
+
+/// Writes the rows of a results CSV with the columns `header`.
+struct ResultsWriter {
+    out: csv::Writer<File>,
+    header: Vec<String>,
+}
+
+impl ResultsWriter {
+    /// Creates `path`, or with `append`, appends to it if it already has rows.
+    ///
+    /// Panics if the file to append to has other columns than `header`, e.g. when it was written
+    /// by an earlier version or with other parameters, rather than truncating it or mixing two
+    /// layouts in it.
+    fn open(path: &Path, header: Vec<String>, append: bool) -> Self {
+        let existing = append
+            .then(|| csv::Reader::from_path(path).ok()?.headers().ok().cloned())
+            .flatten()
+            .filter(|existing| !existing.is_empty());
+        let file = match existing {
+            Some(existing) if existing.iter().ne(&header) => panic!(
+                "{} has other columns than this run, move it away to start a new file",
+                path.display()
+            ),
+            Some(_) => OpenOptions::new().append(true).open(path),
+            None => File::create(path),
+        }
+        .expect("Could not open results file");
+
+        let mut out = csv::WriterBuilder::new()
+            .has_headers(false)
+            .from_writer(file);
+        if existing.is_none() && !header.is_empty() {
+            out.write_record(&header).expect("Could not serialize");
+            out.flush().expect("Could not flush");
+        }
+        ResultsWriter { out, header }
+    }
+
+    fn write<S: Serialize>(&mut self, params: &Params, row: &S) {
+        let (header, record) = csv_record(params, row);
+        assert_eq!(
+            header, self.header,
+            "Rows of a results file should have the same columns"
+        );
+        self.out.write_record(&record).expect("Could not serialize");
+        self.out.flush().expect("Could not flush");
+    }
+}
+
+/// Returns the CSV header and row of `row`: a column per parameter, then the columns of `row`.
+fn csv_record<S: Serialize>(params: &Params, row: &S) -> (Vec<String>, Vec<String>) {
+    params
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .chain(columns(row).into_iter().map(|(name, value)| match value {
+            Value::String(value) => (name, value),
+            value => (name, value.to_string()),
+        }))
+        .unzip()
+}
+
+/// Returns the columns of a results row: its fields in declaration order, where the fields of a
+/// nested struct are prefixed with its name, e.g. `prove_heap_peak` for [`Phases::prove`].
+pub(crate) fn columns<S: Serialize>(row: &S) -> Map<String, Value> {
+    fn flatten_into(columns: &mut Map<String, Value>, prefix: &str, fields: Map<String, Value>) {
+        for (name, value) in fields {
+            let name = format!("{}{}", prefix, name);
+            match value {
+                Value::Object(fields) => flatten_into(columns, &format!("{}_", name), fields),
+                value => {
+                    columns.insert(name, value);
+                }
+            }
+        }
+    }
+
+    let Value::Object(fields) = serde_json::to_value(row).expect("Could not serialize") else {
+        panic!("Results rows should serialize to structs");
+    };
+    let mut columns = Map::new();
+    flatten_into(&mut columns, "", fields);
+    columns
+}
+
+/// Returns the parameter sets of `overrides`, or else of `$BENCH_SIZES`, if any. The parameters
+/// a set leaves out take their value in the first of `defaults`, so that e.g. the Poseidon2 rows
+/// keep their permutation and field when only their sizes are given. A parameter that none of
+/// `defaults` has, e.g. a misspelled name or a bare size given to a host without a `size`, is an
+/// error.
+fn inputs_override<T: ParamSet>(
+    overrides: Option<Vec<Params>>,
+    defaults: &[T],
+) -> Result<Option<Vec<T>>, String> {
+    let sets = match overrides {
+        Some(sets) => sets,
+        None => match std::env::var(SIZES_ENV) {
+            Ok(sizes) => sizes
+                .split(',')
+                .map(|size| size.trim().parse())
+                .collect::<Result<_, _>>()?,
+            Err(_) => return Ok(None),
+        },
+    };
+    let defaults: Vec<Params> = defaults.iter().map(ParamSet::to_params).collect();
+    let base = defaults.first().cloned().unwrap_or_default();
 
-    let mut all_metrics = Vec::new();
+    sets.iter()
+        .map(|set| {
+            if let Some(name) = set
+                .names()
+                .find(|name| !defaults.iter().any(|params| params.get(name).is_some()))
+            {
+                let known: BTreeSet<&str> = defaults.iter().flat_map(Params::names).collect();
+                return Err(format!(
+                    "Unknown parameter {} for this benchmark, expected one of: {}",
+                    name,
+                    known.into_iter().collect::<Vec<_>>().join(", ")
+                ));
+            }
+            let params = set.iter().fold(base.clone(), |params, (name, value)| {
+                params.with(name, value.clone())
+            });
+            T::from_params(&params)
+                .ok_or_else(|| format!("Invalid parameters for this benchmark: {}", params))
+        })
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
+/// Redirects `file` into the orchestrator's output directory, if one is set.
+fn output_path(file: &str) -> PathBuf {
+    match std::env::var(OUTPUT_DIR_ENV) {
+        Ok(dir) => {
+            std::fs::create_dir_all(&dir).expect("Could not create output directory");
+            Path::new(&dir).join(Path::new(file).file_name().unwrap())
+        }
+        Err(_) => PathBuf::from(file),
+    }
+}
+
+pub fn write_csv(out_path: &str, results: &[Metrics]) {
+    let header = results
+        .first()
+        .map(|metric| csv_record(&metric.params, metric).0)
+        .unwrap_or_default();
+    let mut out = ResultsWriter::open(Path::new(out_path), header, false);
 
     for metric in results {
-        out.serialize(&metric).expect("Could not serialize");
-        out.flush().expect("Could not flush");
-        all_metrics.push(metric);
+        out.write(&metric.params, metric);
     }
 
-    out.flush().expect("Could not flush");
+    print_table(results);
+}
 
-    let mut table = Table::new(&all_metrics);
+fn print_table(results: &[Metrics]) {
+    let mut table = Table::new(results);
     table.with(Style::modern());
     println!("{table}");
 }
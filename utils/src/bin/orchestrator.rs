@@ -0,0 +1,103 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use utils::suite::{
+    host_command, SuiteEntry, SuiteManifest, DEVICE_ENV, OUTPUT_DIR_ENV, REPETITIONS_ENV,
+    RESUME_ENV, SIZES_ENV,
+};
+
+/// Runs the benchmark suite declared in a TOML manifest.
+///
+/// ```shell
+/// cargo run --release --bin orchestrator -- [manifest.toml] [--dry-run] [--resume]
+/// ```
+///
+/// Results are collected under `<output_dir>/<device>/<zkvm>/`. With `--resume`, sizes
+/// already benchmarked successfully in that tree are skipped.
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .canonicalize()?;
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let dry_run = args.iter().any(|arg| arg == "--dry-run");
+    let resume = args.iter().any(|arg| arg == "--resume");
+    let manifest_path = args
+        .iter()
+        .find(|arg| !arg.starts_with("--"))
+        .map(PathBuf::from)
+        .unwrap_or_else(|| root.join("benchmarks.toml"));
+
+    let manifest = SuiteManifest::from_file(&manifest_path)?;
+    let output_root = root.join(&manifest.output_dir);
+    std::fs::create_dir_all(&output_root)?;
+    std::fs::copy(&manifest_path, output_root.join("benchmarks.toml"))?;
+
+    let mut failures = Vec::new();
+    for entry in &manifest.runs {
+        println!(
+            "==> {} / {} (device: {})",
+            entry.zkvm, entry.workload, entry.device
+        );
+        if let Err(err) = run_entry(&root, &output_root, entry, dry_run, resume) {
+            eprintln!("{} / {} failed: {}", entry.zkvm, entry.workload, err);
+            failures.push(format!("{}/{}", entry.zkvm, entry.workload));
+        }
+    }
+
+    if !failures.is_empty() {
+        return Err(format!("Failed runs: {}", failures.join(", ")).into());
+    }
+
+    println!("Results are available in {}", output_root.display());
+    Ok(())
+}
+
+fn run_entry(
+    root: &Path,
+    output_root: &Path,
+    entry: &SuiteEntry,
+    dry_run: bool,
+    resume: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let host = host_command(&entry.zkvm, &entry.workload)?;
+    let output_dir = output_root.join(&entry.device).join(&entry.zkvm);
+
+    let mut command = match &host.setup {
+        Some(setup) => {
+            let mut command = Command::new("sh");
+            command
+                .arg("-c")
+                .arg(format!("{} && exec cargo \"$@\"", setup))
+                .arg("sh");
+            command
+        }
+        None => Command::new("cargo"),
+    };
+    command
+        .current_dir(root.join(host.dir))
+        .args(&host.cargo_args)
+        // Let each crate's toolchain file decide, rather than the toolchain running this binary.
+        .env_remove("RUSTUP_TOOLCHAIN")
+        .env("RUSTFLAGS", "-C target-cpu=native")
+        .env(REPETITIONS_ENV, entry.repetitions.to_string())
+        .env(OUTPUT_DIR_ENV, &output_dir)
+        .env(DEVICE_ENV, &entry.device);
+    if !entry.sizes.is_empty() {
+        command.env(SIZES_ENV, entry.sizes.join(","));
+    }
+    if resume {
+        command.env(RESUME_ENV, "1");
+    }
+
+    if dry_run {
+        println!("{:?}", command);
+        return Ok(());
+    }
+
+    let status = command.status()?;
+    if !status.success() {
+        return Err(format!("host exited with {}", status).into());
+    }
+
+    Ok(())
+}
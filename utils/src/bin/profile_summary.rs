@@ -0,0 +1,63 @@
+use std::path::Path;
+use utils::profile_summary::{diff_markdown, ProfileSummary};
+
+const USAGE: &str = "Usage:
+  profile_summary <profile.pb> [--top <n>] [--output <summary.md>]
+  profile_summary diff <before.pb> <after.pb> [--top <n>] [--output <diff.md>]";
+
+const DEFAULT_TOP: usize = 20;
+
+/// Summarizes the pprof profiles written by `--profile` as markdown: the time per crate and the
+/// top functions by self and inclusive time, or the changes between two profiles.
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let top = flag_value(&args, "--top")
+        .map(|n| n.parse().expect("--top should be an integer"))
+        .unwrap_or(DEFAULT_TOP);
+
+    let markdown = match positional(&args).as_slice() {
+        [command, before, after] if command == "diff" => diff_markdown(
+            &ProfileSummary::from_file(Path::new(before))?,
+            &ProfileSummary::from_file(Path::new(after))?,
+            &format!("{} -> {}", file_name(before), file_name(after)),
+            top,
+        ),
+        [profile] => ProfileSummary::from_file(Path::new(profile))?
+            .to_markdown(&format!("Profile {}", file_name(profile)), top),
+        _ => return Err(USAGE.into()),
+    };
+
+    match flag_value(&args, "--output") {
+        Some(output) => {
+            std::fs::write(&output, markdown)?;
+            println!("Summary written to {}", output);
+        }
+        None => print!("{}", markdown),
+    }
+
+    Ok(())
+}
+
+fn flag_value(args: &[String], flag: &str) -> Option<String> {
+    args.iter().skip_while(|arg| *arg != flag).nth(1).cloned()
+}
+
+/// Returns the arguments that are neither a flag nor a flag value.
+fn positional(args: &[String]) -> Vec<String> {
+    let mut positionals = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg.starts_with("--") {
+            iter.next();
+        } else {
+            positionals.push(arg.clone());
+        }
+    }
+    positionals
+}
+
+fn file_name(path: &str) -> String {
+    Path::new(path)
+        .file_name()
+        .map_or(path.to_string(), |name| name.to_string_lossy().into_owned())
+}
@@ -0,0 +1,135 @@
+use std::path::Path;
+use tabled::{settings::Style, Table, Tabled};
+use utils::results::{default_path, export_csv, export_json, ResultsStore, RunFilter};
+
+const USAGE: &str = "Usage:
+  results list [--zkvm <zkvm>] [--workload <workload>]
+  results trend <zkvm> [--workload <workload>] [--metric <metric>]
+  results export <csv|json> <output> [--zkvm <zkvm>] [--workload <workload>] [--run <id>]";
+
+#[derive(Tabled)]
+struct RunRow {
+    id: i64,
+    zkvm: String,
+    workload: String,
+    started_at: String,
+    commit: String,
+    host: String,
+    rows: usize,
+}
+
+#[derive(Tabled)]
+struct TrendRow {
+    run: i64,
+    started_at: String,
+    commit: String,
+    workload: String,
+    params: String,
+    value: String,
+}
+
+/// Queries the historical results database (`.outputs/results.db` by default, or `$BENCH_DB`).
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let store = ResultsStore::open(&default_path())?;
+    let filter = RunFilter {
+        zkvm: flag_value(&args, "--zkvm"),
+        workload: flag_value(&args, "--workload"),
+        run_id: flag_value(&args, "--run").map(|id| id.parse().expect("--run should be a run id")),
+    };
+
+    match args.first().map(String::as_str) {
+        Some("list") => {
+            let rows = store
+                .runs(&filter)?
+                .into_iter()
+                .map(|run| {
+                    let rows = store
+                        .measurements(&RunFilter {
+                            run_id: Some(run.id),
+                            ..Default::default()
+                        })
+                        .map_or(0, |measurements| measurements.len());
+                    RunRow {
+                        id: run.id,
+                        zkvm: run.zkvm,
+                        workload: run.workload,
+                        started_at: run.started_at,
+                        commit: short_commit(run.git_commit),
+                        host: format!(
+                            "{} ({}-{}, {} cpus)",
+                            run.hostname.unwrap_or_default(),
+                            run.os,
+                            run.arch,
+                            run.cpus
+                        ),
+                        rows,
+                    }
+                })
+                .collect::<Vec<_>>();
+            print_table(&rows);
+        }
+        Some("trend") => {
+            let zkvm = positional(&args, 1).ok_or(USAGE)?;
+            let metric = flag_value(&args, "--metric").unwrap_or("proof_duration".to_string());
+            let rows = store
+                .trend(&zkvm, filter.workload.as_deref())?
+                .into_iter()
+                .map(|record| TrendRow {
+                    run: record.run_id,
+                    started_at: record.started_at,
+                    commit: short_commit(record.git_commit),
+                    workload: record.workload,
+                    params: record.params,
+                    value: record
+                        .metrics
+                        .get(&metric)
+                        .map_or("-".to_string(), |value| value.to_string()),
+                })
+                .collect::<Vec<_>>();
+            print_table(&rows);
+        }
+        Some("export") => {
+            let format = positional(&args, 1).ok_or(USAGE)?;
+            let output = positional(&args, 2).ok_or(USAGE)?;
+            let records = store.measurements(&filter)?;
+            match format.as_str() {
+                "csv" => export_csv(&records, Path::new(&output))?,
+                "json" => export_json(&records, Path::new(&output))?,
+                _ => return Err(USAGE.into()),
+            }
+            println!("Exported {} rows to {}", records.len(), output);
+        }
+        _ => return Err(USAGE.into()),
+    }
+
+    Ok(())
+}
+
+fn flag_value(args: &[String], flag: &str) -> Option<String> {
+    args.iter().skip_while(|arg| *arg != flag).nth(1).cloned()
+}
+
+/// Returns the `index`-th argument that is neither a flag nor a flag value.
+fn positional(args: &[String], index: usize) -> Option<String> {
+    let mut positionals = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg.starts_with("--") {
+            iter.next();
+        } else {
+            positionals.push(arg.clone());
+        }
+    }
+    positionals.get(index).cloned()
+}
+
+fn short_commit(commit: Option<String>) -> String {
+    commit.map_or("-".to_string(), |commit| commit.chars().take(8).collect())
+}
+
+fn print_table<T: Tabled>(rows: &[T]) {
+    let mut table = Table::new(rows);
+    table.with(Style::modern());
+    println!("{table}");
+}
@@ -0,0 +1,98 @@
+use guests::{eip1559, keccak::keccak};
+use k256::ecdsa::SigningKey;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use std::fs::File;
+use utils::{metadata::EIP1559_INPUTS, Eip1559Fixture};
+
+const CHAIN_ID: u64 = 1;
+const TO: [u8; 20] = [0x42; 20];
+
+/// Writes a signed EIP-1559 transaction for each calldata size of the benchmark. The key and the
+/// calldata are drawn from a fixed seed, so that the fixtures can be regenerated identically.
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut rng = StdRng::seed_from_u64(1337);
+    let signing_key = SigningKey::random(&mut rng);
+    let sender = eip1559::address(signing_key.verifying_key());
+
+    let fixtures = EIP1559_INPUTS
+        .iter()
+        .enumerate()
+        .map(|(nonce, &calldata_bytes)| {
+            let mut data = vec![0; calldata_bytes];
+            rng.fill_bytes(&mut data);
+            let fields = vec![
+                encode_uint(CHAIN_ID),
+                encode_uint(nonce as u64),
+                encode_uint(1_000_000_000),
+                encode_uint(30_000_000_000),
+                encode_uint(21_000 + 16 * calldata_bytes as u64),
+                encode_bytes(&TO),
+                encode_uint(1_000_000_000_000_000),
+                encode_bytes(&data),
+                encode_list(&[]),
+            ];
+
+            let mut message = vec![eip1559::TRANSACTION_TYPE];
+            message.extend(encode_list(&fields));
+            let (signature, recovery_id) =
+                signing_key.sign_prehash_recoverable(&keccak(&message))?;
+
+            let mut fields = fields;
+            fields.push(encode_uint(recovery_id.is_y_odd() as u64));
+            fields.push(encode_bytes(strip_zeros(&signature.r().to_bytes())));
+            fields.push(encode_bytes(strip_zeros(&signature.s().to_bytes())));
+            let mut transaction = vec![eip1559::TRANSACTION_TYPE];
+            transaction.extend(encode_list(&fields));
+
+            assert_eq!(eip1559::recover_sender(&transaction), Some(sender));
+            Ok(Eip1559Fixture {
+                calldata_bytes,
+                transaction: hex::encode(transaction),
+                sender: hex::encode(sender),
+            })
+        })
+        .collect::<Result<Vec<_>, k256::ecdsa::Error>>()?;
+
+    let file = File::create("eip1559_transactions/transactions.json")?;
+    serde_json::to_writer_pretty(file, &fixtures)?;
+
+    println!("EIP-1559 transactions have been written to eip1559_transactions/transactions.json");
+    Ok(())
+}
+
+fn encode_uint(value: u64) -> Vec<u8> {
+    encode_bytes(strip_zeros(&value.to_be_bytes()))
+}
+
+fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    if let [byte @ 0x00..=0x7f] = bytes {
+        return vec![*byte];
+    }
+    let mut encoding = header(0x80, bytes.len());
+    encoding.extend_from_slice(bytes);
+    encoding
+}
+
+fn encode_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload = items.concat();
+    let mut encoding = header(0xc0, payload.len());
+    encoding.extend(payload);
+    encoding
+}
+
+/// Header of a string (`offset = 0x80`) or a list (`offset = 0xc0`) with a payload of `len` bytes.
+fn header(offset: u8, len: usize) -> Vec<u8> {
+    if len < 56 {
+        vec![offset + len as u8]
+    } else {
+        let len_bytes = strip_zeros(&len.to_be_bytes()).to_vec();
+        let mut header = vec![offset + 55 + len_bytes.len() as u8];
+        header.extend(len_bytes);
+        header
+    }
+}
+
+fn strip_zeros(bytes: &[u8]) -> &[u8] {
+    let zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+    &bytes[zeros..]
+}
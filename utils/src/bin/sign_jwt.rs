@@ -0,0 +1,67 @@
+use guests::{base64url, jwt, sha2::sha2};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use rsa::{traits::PublicKeyParts, Pkcs1v15Sign, RsaPrivateKey};
+use std::fs::File;
+use utils::{metadata::JWT_INPUTS, JwtFixture};
+
+const ISSUER: &str = "https://accounts.zkvm-benchmarks.example";
+const AUDIENCE: &str = "zkvm-benchmarks";
+const SUBJECT: &str = "110248495921238986420";
+
+/// Writes an RS256 ID token for each payload size of the benchmark, issued with an RSA-2048 key.
+/// The key and the nonces filling the claims up to the payload size are drawn from a fixed seed,
+/// so that the fixtures can be regenerated identically.
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut rng = StdRng::seed_from_u64(1337);
+    let private_key = RsaPrivateKey::new(&mut rng, 2048)?;
+    let public_key = private_key.to_public_key();
+
+    let header = base64url::encode(br#"{"alg":"RS256","typ":"JWT","kid":"zkvm-benchmarks"}"#);
+    let fixtures = JWT_INPUTS
+        .iter()
+        .map(|&payload_bytes| {
+            let claims = |nonce: &str| {
+                format!(
+                    r#"{{"iss":"{}","aud":"{}","sub":"{}","iat":1700000000,"exp":1700003600,"nonce":"{}"}}"#,
+                    ISSUER, AUDIENCE, SUBJECT, nonce
+                )
+            };
+            let nonce_len = payload_bytes
+                .checked_sub(claims("").len())
+                .ok_or("payload too small for the claims")?;
+            let mut nonce = vec![0; nonce_len];
+            rng.fill_bytes(&mut nonce);
+            let nonce = &base64url::encode(&nonce)[..nonce_len];
+            let claims = claims(nonce);
+            assert_eq!(claims.len(), payload_bytes);
+
+            let signing_input = format!("{}.{}", header, base64url::encode(claims.as_bytes()));
+            let signature = private_key.sign(
+                Pkcs1v15Sign::new::<rsa::sha2::Sha256>(),
+                &sha2(signing_input.as_bytes()),
+            )?;
+            let token = format!("{}.{}", signing_input, base64url::encode(&signature));
+
+            let fixture = JwtFixture {
+                payload_bytes,
+                token,
+                modulus: hex::encode(public_key.n().to_bytes_be()),
+                exponent: hex::encode(public_key.e().to_bytes_be()),
+                sub: SUBJECT.to_string(),
+            };
+            let input = jwt::JwtInput {
+                token: fixture.token.clone(),
+                modulus: public_key.n().to_bytes_be(),
+                exponent: public_key.e().to_bytes_be(),
+            };
+            assert_eq!(jwt::verify_token(&input).as_deref(), Some(SUBJECT));
+            Ok(fixture)
+        })
+        .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+
+    let file = File::create("jwt_tokens/tokens.json")?;
+    serde_json::to_writer_pretty(file, &fixtures)?;
+
+    println!("JWT tokens have been written to jwt_tokens/tokens.json");
+    Ok(())
+}
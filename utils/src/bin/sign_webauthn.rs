@@ -0,0 +1,57 @@
+use guests::{base64url, sha2::sha2};
+use p256::ecdsa::signature::Signer;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use std::fs::File;
+use utils::{metadata::WEBAUTHN_INPUTS, WebAuthnFixture};
+
+const RP_ID: &str = "zkvm-benchmarks.example";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    write_webauthn_assertions()?;
+    println!("WebAuthn assertions have been written to webauthn_assertions/assertions.json");
+    Ok(())
+}
+
+/// Writes as many WebAuthn assertions as the largest benchmark size, each signing a random
+/// challenge with its own P-256 passkey. The keys and challenges are drawn from a fixed seed, so
+/// that the fixtures can be regenerated identically.
+fn write_webauthn_assertions() -> Result<(), Box<dyn std::error::Error>> {
+    let mut rng = StdRng::seed_from_u64(1337);
+    let num_assertions = WEBAUTHN_INPUTS.into_iter().max().unwrap_or_default();
+
+    let fixtures = (0..num_assertions)
+        .map(|sign_count| {
+            let signing_key = p256::ecdsa::SigningKey::random(&mut rng);
+            let mut challenge = [0; 32];
+            rng.fill_bytes(&mut challenge);
+
+            // Hash of the relying party identifier, flags (user present and verified) and
+            // signature counter.
+            let mut authenticator_data = sha2(RP_ID.as_bytes()).to_vec();
+            authenticator_data.push(0x05);
+            authenticator_data.extend_from_slice(&(sign_count as u32).to_be_bytes());
+            let client_data_json = format!(
+                r#"{{"type":"webauthn.get","challenge":"{}","origin":"https://{}","crossOrigin":false}}"#,
+                base64url::encode(&challenge),
+                RP_ID
+            );
+
+            let mut signed_data = authenticator_data.clone();
+            signed_data.extend_from_slice(&sha2(client_data_json.as_bytes()));
+            let signature: p256::ecdsa::Signature = signing_key.sign(&signed_data);
+
+            WebAuthnFixture {
+                public_key: hex::encode(signing_key.verifying_key().to_encoded_point(false)),
+                rp_id: RP_ID.to_string(),
+                challenge: hex::encode(challenge),
+                authenticator_data: hex::encode(authenticator_data),
+                client_data_json,
+                signature: hex::encode(signature.to_der()),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let file = File::create("webauthn_assertions/assertions.json")?;
+    serde_json::to_writer_pretty(file, &fixtures)?;
+    Ok(())
+}
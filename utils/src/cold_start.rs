@@ -0,0 +1,117 @@
+use serde::Serialize;
+use serde_with::{serde_as, DurationNanoSeconds};
+use std::time::Duration;
+use tabled::Tabled;
+
+use crate::bench::{display_duration, display_params, Status};
+use crate::params::Params;
+use crate::profile::Phase;
+use crate::timeline::PhaseSpan;
+
+/// Measures the time to the first proof of a fresh process instead of the steady-state metrics
+/// when set. Only the first input is benchmarked, and the row is appended to
+/// `<workload>_<zkvm>_cold_start.csv` next to the results.
+pub const COLD_START_ENV: &str = "BENCH_COLD_START";
+
+/// Time from the start of the process to its first proof, split by phase.
+///
+/// The phases are the spans marked by the host with [`crate::profile::phase`] before the end of
+/// the first proof, so a host that does not mark its SDK initialization reports it as zero.
+#[serde_as]
+#[derive(Serialize, Tabled)]
+pub struct ColdStartMetrics {
+    #[serde(skip)]
+    #[tabled(display_with = "display_params")]
+    pub params: Params,
+    /// From the start of the process to the start of the benchmark.
+    #[serde_as(as = "DurationNanoSeconds")]
+    #[tabled(display_with = "display_duration")]
+    pub process_startup: Duration,
+    #[serde_as(as = "DurationNanoSeconds")]
+    #[tabled(display_with = "display_duration")]
+    pub sdk_init: Duration,
+    #[serde_as(as = "DurationNanoSeconds")]
+    #[tabled(display_with = "display_duration")]
+    pub program_load: Duration,
+    #[serde_as(as = "DurationNanoSeconds")]
+    #[tabled(display_with = "display_duration")]
+    pub key_setup: Duration,
+    #[serde_as(as = "DurationNanoSeconds")]
+    #[tabled(display_with = "display_duration")]
+    pub proving: Duration,
+    #[serde_as(as = "DurationNanoSeconds")]
+    #[tabled(display_with = "display_duration")]
+    pub time_to_first_proof: Duration,
+    pub status: Status,
+}
+
+impl ColdStartMetrics {
+    /// Splits a run into phases. `run_duration` is used as the end of the first proof when the
+    /// host does not mark its proving phase.
+    pub fn from_phases(
+        params: Params,
+        process_startup: Duration,
+        phases: &[PhaseSpan],
+        run_duration: Duration,
+    ) -> Self {
+        let first_proof_end = phases
+            .iter()
+            .find(|span| span.phase == Phase::Prove.name())
+            .map_or(run_duration, |span| ms(span.end_ms));
+        let total = |phase: Phase| {
+            phases
+                .iter()
+                .filter(|span| span.phase == phase.name() && ms(span.end_ms) <= first_proof_end)
+                .map(|span| ms(span.end_ms) - ms(span.start_ms))
+                .sum()
+        };
+
+        ColdStartMetrics {
+            params,
+            process_startup,
+            sdk_init: total(Phase::Init),
+            program_load: total(Phase::Load),
+            key_setup: total(Phase::Setup),
+            proving: total(Phase::Prove),
+            time_to_first_proof: process_startup + first_proof_end,
+            status: Status::Ok,
+        }
+    }
+}
+
+pub fn enabled() -> bool {
+    std::env::var(COLD_START_ENV).is_ok()
+}
+
+/// Returns how long the process has been running, from the start time kept by the kernel.
+#[cfg(target_os = "linux")]
+pub fn process_uptime() -> Option<Duration> {
+    let stat = std::fs::read_to_string("/proc/self/stat").ok()?;
+    // The command name may contain spaces, the fields are counted after it. `starttime` is the
+    // 22nd field, in clock ticks since boot.
+    let start_ticks: f64 = stat[stat.rfind(')')? + 1..]
+        .split_whitespace()
+        .nth(19)?
+        .parse()
+        .ok()?;
+    let ticks_per_second = unsafe { libc::sysconf(libc::_SC_CLK_TCK) } as f64;
+    let system_uptime: f64 = std::fs::read_to_string("/proc/uptime")
+        .ok()?
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs_f64(
+        (system_uptime - start_ticks / ticks_per_second).max(0.0),
+    ))
+}
+
+/// Returns how long the process has been running, which is only known on Linux.
+#[cfg(not(target_os = "linux"))]
+pub fn process_uptime() -> Option<Duration> {
+    None
+}
+
+fn ms(ms: f64) -> Duration {
+    Duration::from_secs_f64(ms / 1e3)
+}
@@ -0,0 +1,140 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use crate::profile::Phase;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+/// Heap usage of the phases measured since the last [`take_phase_stats`].
+static PHASES: Mutex<Vec<(Phase, HeapStats)>> = Mutex::new(Vec::new());
+
+/// Global allocator wrapper counting the heap allocations of the whole process.
+///
+/// Opt-in, as it adds a few atomic operations to every allocation. Either enable the
+/// `heap-tracking` feature of `utils`, which installs it over the system allocator:
+///
+/// ```shell
+/// cargo run --release --bin ecdsa --features utils/heap-tracking
+/// ```
+///
+/// or install it in the host binary:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static GLOBAL: utils::heap::CountingAlloc = utils::heap::CountingAlloc::new(std::alloc::System);
+/// ```
+pub struct CountingAlloc<A = System> {
+    inner: A,
+}
+
+impl<A> CountingAlloc<A> {
+    pub const fn new(inner: A) -> Self {
+        CountingAlloc { inner }
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAlloc<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = self.inner.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // Counted as freeing the old block and allocating the new one.
+            LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+fn record_alloc(size: usize) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Heap usage of a measured section, across all threads.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct HeapStats {
+    /// Peak of the live heap during the section, in bytes.
+    pub peak_bytes: usize,
+    /// Total bytes allocated during the section.
+    pub allocated_bytes: u64,
+    /// Number of allocations during the section.
+    pub allocations: u64,
+}
+
+/// Returns whether [`CountingAlloc`] is the global allocator.
+pub fn is_installed() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+/// Runs `func` and returns its heap usage, or `None` if [`CountingAlloc`] is not installed.
+///
+/// Sections can be nested: the peak of the enclosing section still accounts for the nested one.
+pub fn measure_heap<R, F: FnOnce() -> R>(func: F) -> (R, Option<HeapStats>) {
+    if !is_installed() {
+        return (func(), None);
+    }
+
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let outer_peak = PEAK.swap(LIVE.load(Ordering::Relaxed), Ordering::Relaxed);
+
+    let result = func();
+
+    let peak = PEAK.fetch_max(outer_peak, Ordering::Relaxed);
+    let stats = HeapStats {
+        peak_bytes: peak,
+        allocated_bytes: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+    };
+
+    (result, Some(stats))
+}
+
+/// Measures the heap usage of one phase of a benchmark run, see [`crate::profile::phase`].
+pub(crate) fn measure_phase<R, F: FnOnce() -> R>(phase: Phase, func: F) -> R {
+    let (result, stats) = measure_heap(func);
+    if let Some(stats) = stats {
+        PHASES.lock().unwrap().push((phase, stats));
+    }
+    result
+}
+
+/// Returns the heap usage of the phases measured since the last call.
+pub(crate) fn take_phase_stats() -> Vec<(Phase, HeapStats)> {
+    std::mem::take(&mut *PHASES.lock().unwrap())
+}
+
+#[cfg(feature = "heap-tracking")]
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc::new(System);
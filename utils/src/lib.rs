@@ -1,16 +1,41 @@
+use bls12_381::{G1Affine, G2Affine, G2Projective, Scalar};
+use guests::bls::{self, BlsAggregateInput};
 use guests::ecdsa::EcdsaVerifyInput;
-use k256::{ecdsa::Signature, elliptic_curve::sec1::EncodedPoint, Secp256k1};
+use guests::ed25519::Ed25519VerifyInput;
+use guests::jwt::JwtInput;
+use guests::merkle::MerkleInput;
+use guests::mlp::{self, MlpInput};
+use guests::webauthn::WebAuthnAssertion;
+use k256::ecdsa::{signature::Signer, Signature, SigningKey};
 use rand::{rngs::StdRng, RngCore, SeedableRng};
+use sha3::{Digest, Keccak256};
 use std::fs;
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 
-use serde::Serialize;
+use metadata::{BLS_INPUTS, BLS_PAIRINGS, MERKLE_DEPTHS, MERKLE_HASHES, MERKLE_PROOFS};
+use params::{ParamValue, Params, SIZE};
+
+use serde::{Deserialize, Serialize};
 
 pub mod bench;
+pub mod cold_start;
+pub mod heap;
+pub mod merkle;
 pub mod metadata;
+pub mod openmetrics;
+pub mod params;
+pub mod preflight;
 pub mod profile;
+pub mod profile_summary;
+pub mod results;
+pub mod rusage;
+pub mod stats;
+pub mod suite;
+pub mod sysfs;
+pub mod thermal;
+pub mod timeline;
 
 pub fn sha2_input(num_bytes: usize) -> Vec<u8> {
     let mut rng = StdRng::seed_from_u64(1337);
@@ -19,29 +44,297 @@ pub fn sha2_input(num_bytes: usize) -> Vec<u8> {
     message
 }
 
-pub fn ecdsa_input() -> EcdsaVerifyInput {
-    const MESSAGE: &[u8] = include_bytes!("../../utils/ecdsa_signature/message.txt");
-    const KEY: &[u8] = include_bytes!("../../utils/ecdsa_signature/verifying_key.txt");
-    const SIGNATURE: &[u8] = include_bytes!("../../utils/ecdsa_signature/signature.txt");
+/// Same message as [`sha2_input`], so that both hashes are compared on the same data.
+pub fn keccak_input(num_bytes: usize) -> Vec<u8> {
+    sha2_input(num_bytes)
+}
+
+/// Keccak-256 digest of `input`, computed natively with `sha3` rather than the guest code, to
+/// check the output of the guests.
+pub fn keccak_digest(input: &[u8]) -> [u8; 32] {
+    Keccak256::digest(input).into()
+}
+
+/// Result of `n` Poseidon2 permutations, computed natively to check the output of the guests.
+pub fn poseidon2_chain(n: u32) -> u32 {
+    guests::poseidon2::hash_chain(n)
+}
+
+/// Parameter sets of the Poseidon2 chains of `sizes` permutations, on a zkVM computing
+/// `permutation` over `field`. The zkVMs without Poseidon2 run their own algebraic hash, and the
+/// zkVMs with Poseidon2 instantiate it over their own field, so both are recorded with each row.
+pub fn poseidon2_params(sizes: &[u32], permutation: &str, field: &str) -> Vec<Params> {
+    sizes
+        .iter()
+        .map(|&n| {
+            Params::new()
+                .with(SIZE, n)
+                .with("permutation", permutation)
+                .with("field", field)
+        })
+        .collect()
+}
+
+/// Parameter sets of the Merkle workload: the hash, the depth of the tree and the number of
+/// inclusion proofs.
+pub fn merkle_params() -> Vec<Params> {
+    let axis = |name: &str, values: Vec<ParamValue>| (name.to_string(), values);
+    Params::product(&[
+        axis("hash", MERKLE_HASHES.map(Into::into).to_vec()),
+        axis("depth", MERKLE_DEPTHS.map(Into::into).to_vec()),
+        axis("proofs", MERKLE_PROOFS.map(Into::into).to_vec()),
+    ])
+}
+
+/// Batch of inclusion proofs of leaves spread over a tree, described by [`merkle_params`]. The
+/// tree is the same for a given hash and depth, and is only built once for the repetitions of a
+/// parameter set.
+pub fn merkle_input(params: &Params) -> MerkleInput {
+    let hash = params
+        .get("hash")
+        .map(ToString::to_string)
+        .unwrap_or_else(|| panic!("Missing hash parameter in {}", params));
+    let hash = hash
+        .parse()
+        .unwrap_or_else(|_| panic!("Unknown Merkle hash {}", hash));
+    let tree = merkle::MerkleTree::cached(hash, params.u64("depth") as usize, 1337);
+
+    let mut rng = StdRng::seed_from_u64(1337);
+    let proofs = (0..params.u64("proofs"))
+        .map(|_| tree.proof(rng.next_u64() as usize % tree.num_leaves()))
+        .collect();
+    MerkleInput {
+        hash,
+        root: tree.root(),
+        proofs,
+    }
+}
+
+/// Batch of `num_signatures` signatures of random 64-byte messages, each by its own key. The keys
+/// and messages are drawn from a fixed seed, so that every zkVM verifies the same batch.
+pub fn ecdsa_input(num_signatures: usize) -> Vec<EcdsaVerifyInput> {
+    let mut rng = StdRng::seed_from_u64(1337);
+    (0..num_signatures)
+        .map(|_| {
+            let signing_key = SigningKey::random(&mut rng);
+            let mut message = vec![0; 64];
+            rng.fill_bytes(&mut message);
+            let signature: Signature = signing_key.sign(&message);
+            EcdsaVerifyInput {
+                encoded_point: signing_key.verifying_key().to_encoded_point(false),
+                message,
+                signature,
+            }
+        })
+        .collect()
+}
+
+/// Batch of `num_signatures` Ed25519 signatures of random 64-byte messages, each by its own key,
+/// drawn from a fixed seed like [`ecdsa_input`].
+pub fn ed25519_input(num_signatures: usize) -> Vec<Ed25519VerifyInput> {
+    let mut rng = StdRng::seed_from_u64(1337);
+    (0..num_signatures)
+        .map(|_| {
+            let mut secret_key = [0; 32];
+            rng.fill_bytes(&mut secret_key);
+            let signing_key = ed25519_dalek::SigningKey::from_bytes(&secret_key);
+            let mut message = vec![0; 64];
+            rng.fill_bytes(&mut message);
+            Ed25519VerifyInput {
+                public_key: signing_key.verifying_key().to_bytes(),
+                signature: signing_key.sign(&message).to_bytes().to_vec(),
+                message,
+            }
+        })
+        .collect()
+}
 
-    // Use a static variable to store the decoded message so it has a 'static lifetime
-    let message = hex::decode(MESSAGE).expect("Failed to decode hex of 'message'");
+/// Parameter sets of the BLS workload: the number of signers, and which of `pairings` (see
+/// [`BLS_PAIRINGS`]) computes the pairings, so that software runs are not compared with
+/// precompile-backed ones.
+pub fn bls_params(pairings: &[&str]) -> Vec<Params> {
+    let axis = |name: &str, values: Vec<ParamValue>| (name.to_string(), values);
+    Params::product(&[
+        axis("signers", BLS_INPUTS.map(Into::into).to_vec()),
+        axis("pairing", pairings.iter().map(|&p| p.into()).collect()),
+    ])
+}
 
-    let encoded_point = EncodedPoint::<Secp256k1>::from_bytes(
-        &hex::decode(KEY).expect("Failed to decode hex of 'verifying_key'"),
-    )
-    .expect("Invalid encoded verifying_key bytes");
+/// Whether the BLS run of `params` calls the pairing precompiles of the zkVM, rather than
+/// computing the pairings in software.
+pub fn bls_precompile(params: &Params) -> bool {
+    match params.get("pairing").map(ToString::to_string).as_deref() {
+        Some("software") => false,
+        Some("precompile") => true,
+        pairing => panic!(
+            "Unknown BLS pairing {:?}, expected one of {:?}",
+            pairing, BLS_PAIRINGS
+        ),
+    }
+}
 
-    let bytes = hex::decode(SIGNATURE).expect("Failed to decode hex of 'signature'");
-    let signature = Signature::from_slice(&bytes).expect("Invalid signature bytes");
+/// Aggregate signature of a random 32-byte message, like a block root, by the number of signers
+/// of [`bls_params`]. The keys are drawn from a fixed seed like [`ecdsa_input`], so a set of
+/// signers extends the smaller ones.
+pub fn bls_input(params: &Params) -> BlsAggregateInput {
+    let num_signers = params.u64("signers") as usize;
+    let mut rng = StdRng::seed_from_u64(1337);
+    let mut message = vec![0; 32];
+    rng.fill_bytes(&mut message);
+    let message_point = bls::hash_to_g2(&message);
 
-    EcdsaVerifyInput {
-        encoded_point,
-        message: message.clone(),
-        signature,
+    let mut signature = G2Projective::identity();
+    let public_keys = (0..num_signers)
+        .map(|_| {
+            let mut bytes = [0; 64];
+            rng.fill_bytes(&mut bytes);
+            let secret_key = Scalar::from_bytes_wide(&bytes);
+            signature += message_point * secret_key;
+            G1Affine::from(G1Affine::generator() * secret_key)
+                .to_compressed()
+                .to_vec()
+        })
+        .collect();
+    BlsAggregateInput {
+        public_keys,
+        message,
+        signature: G2Affine::from(signature).to_compressed().to_vec(),
     }
 }
 
+/// Input of the model with hidden layers of `width` neurons, on the sample vector of
+/// `guests::mlp`.
+pub fn mlp_input(width: usize) -> MlpInput {
+    MlpInput {
+        width,
+        input: mlp::sample_input(),
+    }
+}
+
+/// Logits of [`mlp_input`] computed natively, to check the output of the guests.
+pub fn mlp_logits(width: usize) -> [i32; mlp::NUM_LOGITS] {
+    mlp::infer(&mlp_input(width))
+}
+
+/// Digest of [`mlp_logits`], for the guests which return a single value.
+pub fn mlp_digest(width: usize) -> u64 {
+    mlp::logits_digest(&mlp_logits(width))
+}
+
+/// WebAuthn assertion written by the `sign_webauthn` binary, with its binary fields in hex.
+#[derive(Serialize, Deserialize)]
+pub struct WebAuthnFixture {
+    pub public_key: String,
+    pub rp_id: String,
+    pub challenge: String,
+    pub authenticator_data: String,
+    pub client_data_json: String,
+    pub signature: String,
+}
+
+/// The first `num_assertions` WebAuthn assertions of the fixtures, each by its own passkey.
+pub fn webauthn_input(num_assertions: usize) -> Vec<WebAuthnAssertion> {
+    const FIXTURES: &str = include_str!("../../utils/webauthn_assertions/assertions.json");
+    let fixtures: Vec<WebAuthnFixture> =
+        serde_json::from_str(FIXTURES).expect("Failed to parse the WebAuthn assertions");
+    assert!(
+        num_assertions <= fixtures.len(),
+        "Only {} WebAuthn assertions are available",
+        fixtures.len()
+    );
+
+    let decode = |hex: &str| hex::decode(hex).expect("Failed to decode hex of WebAuthn assertion");
+    fixtures
+        .into_iter()
+        .take(num_assertions)
+        .map(|fixture| WebAuthnAssertion {
+            public_key: decode(&fixture.public_key),
+            rp_id: fixture.rp_id,
+            challenge: decode(&fixture.challenge),
+            authenticator_data: decode(&fixture.authenticator_data),
+            client_data_json: fixture.client_data_json,
+            signature: decode(&fixture.signature),
+        })
+        .collect()
+}
+
+/// Signed EIP-1559 transaction written by the `sign_eip1559` binary, with `calldata_bytes` bytes
+/// of calldata.
+#[derive(Serialize, Deserialize)]
+pub struct Eip1559Fixture {
+    pub calldata_bytes: usize,
+    /// Hex of the raw transaction.
+    pub transaction: String,
+    /// Hex of the address of the signer.
+    pub sender: String,
+}
+
+fn eip1559_fixture(calldata_bytes: usize) -> Eip1559Fixture {
+    const FIXTURES: &str = include_str!("../../utils/eip1559_transactions/transactions.json");
+    let fixtures: Vec<Eip1559Fixture> =
+        serde_json::from_str(FIXTURES).expect("Failed to parse the EIP-1559 transactions");
+    fixtures
+        .into_iter()
+        .find(|fixture| fixture.calldata_bytes == calldata_bytes)
+        .unwrap_or_else(|| {
+            panic!(
+                "No EIP-1559 transaction with {} bytes of calldata",
+                calldata_bytes
+            )
+        })
+}
+
+/// Raw signed EIP-1559 transaction with `calldata_bytes` bytes of calldata.
+pub fn eip1559_input(calldata_bytes: usize) -> Vec<u8> {
+    hex::decode(eip1559_fixture(calldata_bytes).transaction)
+        .expect("Failed to decode hex of 'transaction'")
+}
+
+/// Sender of the transaction of [`eip1559_input`], to check the output of the guests.
+pub fn eip1559_sender(calldata_bytes: usize) -> [u8; 20] {
+    hex::decode(eip1559_fixture(calldata_bytes).sender)
+        .expect("Failed to decode hex of 'sender'")
+        .try_into()
+        .expect("Invalid sender length")
+}
+
+/// RS256 token written by the `sign_jwt` binary, with claims of `payload_bytes` bytes.
+#[derive(Serialize, Deserialize)]
+pub struct JwtFixture {
+    pub payload_bytes: usize,
+    pub token: String,
+    /// Hex of the big-endian modulus of the public key of the issuer.
+    pub modulus: String,
+    /// Hex of the big-endian public exponent of the public key of the issuer.
+    pub exponent: String,
+    pub sub: String,
+}
+
+fn jwt_fixture(payload_bytes: usize) -> JwtFixture {
+    const FIXTURES: &str = include_str!("../../utils/jwt_tokens/tokens.json");
+    let fixtures: Vec<JwtFixture> =
+        serde_json::from_str(FIXTURES).expect("Failed to parse the JWT tokens");
+    fixtures
+        .into_iter()
+        .find(|fixture| fixture.payload_bytes == payload_bytes)
+        .unwrap_or_else(|| panic!("No JWT with a payload of {} bytes", payload_bytes))
+}
+
+/// Token with claims of `payload_bytes` bytes, and the public key of its issuer.
+pub fn jwt_input(payload_bytes: usize) -> JwtInput {
+    let fixture = jwt_fixture(payload_bytes);
+    JwtInput {
+        token: fixture.token,
+        modulus: hex::decode(fixture.modulus).expect("Failed to decode hex of 'modulus'"),
+        exponent: hex::decode(fixture.exponent).expect("Failed to decode hex of 'exponent'"),
+    }
+}
+
+/// Subject of the token of [`jwt_input`], to check the output of the guests.
+pub fn jwt_subject(payload_bytes: usize) -> String {
+    jwt_fixture(payload_bytes).sub
+}
+
 pub fn load_elf(path: &str) -> Vec<u8> {
     fs::read(path).unwrap_or_else(|err| {
         panic!("Failed to load ELF file from {}: {}", path, err);
@@ -0,0 +1,86 @@
+use guests::merkle::{InclusionProof, MerkleHash};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use std::sync::{Arc, Mutex};
+
+/// Last tree generated by [`MerkleTree::cached`]. The runs of a parameter set and its repetitions
+/// follow each other, so keeping a single tree avoids rebuilding it without holding every depth
+/// in memory.
+static LAST_TREE: Mutex<Option<(u64, Arc<MerkleTree>)>> = Mutex::new(None);
+
+/// Complete binary Merkle tree over pseudorandom leaves, to generate the inputs of the Merkle
+/// workload. The leaves only depend on the seed and the depth, so that every zkVM proves the same
+/// tree.
+pub struct MerkleTree {
+    hash: MerkleHash,
+    /// Nodes of each level, from the leaves up to the root.
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    pub fn generate(hash: MerkleHash, depth: usize, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut leaves = vec![[0; 32]; 1 << depth];
+        for leaf in &mut leaves {
+            rng.fill_bytes(leaf);
+        }
+
+        let mut levels = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let parents = levels
+                .last()
+                .unwrap()
+                .chunks_exact(2)
+                .map(|pair| hash.compress(&pair[0], &pair[1]))
+                .collect();
+            levels.push(parents);
+        }
+        MerkleTree { hash, levels }
+    }
+
+    /// Same tree as [`MerkleTree::generate`], reusing the previous one if it has the same
+    /// parameters, since a tree of depth 20 has a million leaves to hash.
+    pub fn cached(hash: MerkleHash, depth: usize, seed: u64) -> Arc<Self> {
+        let mut last = LAST_TREE.lock().unwrap();
+        match last.as_ref() {
+            Some((last_seed, tree))
+                if *last_seed == seed && tree.hash == hash && tree.depth() == depth =>
+            {
+                tree.clone()
+            }
+            _ => {
+                let tree = Arc::new(Self::generate(hash, depth, seed));
+                *last = Some((seed, tree.clone()));
+                tree
+            }
+        }
+    }
+
+    pub fn hash(&self) -> MerkleHash {
+        self.hash
+    }
+
+    pub fn depth(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    pub fn num_leaves(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.levels.last().unwrap()[0]
+    }
+
+    pub fn proof(&self, index: usize) -> InclusionProof {
+        let siblings = self.levels[..self.depth()]
+            .iter()
+            .enumerate()
+            .map(|(level, nodes)| nodes[(index >> level) ^ 1])
+            .collect();
+        InclusionProof {
+            leaf: self.levels[0][index],
+            index: index as u64,
+            siblings,
+        }
+    }
+}
@@ -1,4 +1,21 @@
 pub const FIBONACCI_INPUTS: [u32; 5] = [10, 100, 1000, 10000, 100000];
+/// Terms of the NovaNet Fibonacci guest, whose WASM execution is proven step by step.
+pub const NOVANET_FIBONACCI_INPUTS: [usize; 2] = [10, 100];
 pub const SHA2_INPUTS: [usize; 5] = [32, 256, 512, 1024, 2048];
-pub const ECDSA_INPUTS: [usize; 1] = [1];
+/// Same sizes as SHA-2, so that both hashes are compared on the same messages.
+pub const KECCAK_INPUTS: [usize; 5] = SHA2_INPUTS;
+pub const POSEIDON2_INPUTS: [u32; 4] = [10, 100, 1000, 10000];
+pub const MERKLE_HASHES: [&str; 3] = ["sha256", "keccak", "poseidon2"];
+pub const MERKLE_DEPTHS: [u64; 3] = [8, 16, 20];
+pub const MERKLE_PROOFS: [u64; 1] = [16];
+pub const ECDSA_INPUTS: [usize; 4] = [1, 4, 16, 64];
+pub const ED25519_INPUTS: [usize; 4] = [1, 4, 16, 64];
+pub const WEBAUTHN_INPUTS: [usize; 3] = [1, 4, 16];
+pub const BLS_INPUTS: [usize; 4] = [1, 16, 128, 512];
+/// How the BLS12-381 pairings are computed: `software` runs the upstream `bls12_381` crate,
+/// `precompile` the fork of the zkVM calling its BLS12-381 precompiles, which only SP1 runs.
+pub const BLS_PAIRINGS: [&str; 2] = ["software", "precompile"];
+pub const MLP_INPUTS: [usize; 4] = [32, 64, 128, 256];
+pub const EIP1559_INPUTS: [usize; 4] = [0, 128, 1024, 4096];
+pub const JWT_INPUTS: [usize; 3] = [256, 1024, 4096];
 pub const ETHTRANSFER_INPUTS: [usize; 3] = [1, 10, 100];
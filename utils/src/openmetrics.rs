@@ -0,0 +1,126 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::bench::{Metrics, Status};
+use crate::params::Params;
+
+/// Directory to export OpenMetrics text files to, e.g. the node_exporter textfile collector
+/// directory. Nothing is exported when unset.
+pub const OPENMETRICS_ENV: &str = "BENCH_OPENMETRICS_DIR";
+
+/// (name, help, value) of each exported gauge.
+type Gauge = (&'static str, &'static str, fn(&Metrics) -> f64);
+
+const GAUGES: [Gauge; 4] = [
+    (
+        "zkvm_bench_proof_duration_seconds",
+        "Duration of the proof generation.",
+        |metrics| metrics.proof_duration.as_secs_f64(),
+    ),
+    (
+        "zkvm_bench_peak_memory_bytes",
+        "Peak resident memory of the benchmark process.",
+        |metrics| metrics.peak_memory as f64,
+    ),
+    (
+        "zkvm_bench_cycles",
+        "Number of cycles of the guest execution.",
+        |metrics| metrics.cycles as f64,
+    ),
+    ("zkvm_bench_proof_bytes", "Size of the proof.", |metrics| {
+        metrics.proof_bytes as f64
+    }),
+];
+
+/// Exports the finished rows of a run as OpenMetrics gauges, rewriting
+/// `<dir>/zkvm_bench_<workload>_<zkvm>.prom` after each row.
+pub struct OpenMetricsExporter {
+    path: PathBuf,
+    zkvm: String,
+    workload: String,
+    /// Latest values per parameter set: repetitions overwrite each other, as a gauge holds one
+    /// value.
+    rows: Vec<(Params, [f64; GAUGES.len()])>,
+}
+
+impl OpenMetricsExporter {
+    pub fn new(dir: &Path, zkvm: &str, workload: &str) -> Self {
+        Self {
+            path: dir.join(format!("zkvm_bench_{}_{}.prom", workload, zkvm)),
+            zkvm: zkvm.to_string(),
+            workload: workload.to_string(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Returns an exporter if `$BENCH_OPENMETRICS_DIR` is set.
+    pub fn from_env(zkvm: &str, workload: &str) -> Option<Self> {
+        let dir = std::env::var(OPENMETRICS_ENV).ok()?;
+        Some(Self::new(Path::new(&dir), zkvm, workload))
+    }
+
+    /// Adds a finished row and rewrites the exported file. Failed rows are not exported.
+    pub fn record(&mut self, metrics: &Metrics) -> std::io::Result<()> {
+        if metrics.status != Status::Ok {
+            return Ok(());
+        }
+        let values = GAUGES.map(|(_, _, value)| value(metrics));
+        match self
+            .rows
+            .iter_mut()
+            .find(|(params, _)| *params == metrics.params)
+        {
+            Some((_, previous)) => *previous = values,
+            None => self.rows.push((metrics.params.clone(), values)),
+        }
+        write_atomic(&self.path, self.render().as_bytes())
+    }
+
+    fn render(&self) -> String {
+        let mut out = String::new();
+        for (i, (name, help, _)) in GAUGES.iter().enumerate() {
+            out += &format!("# HELP {} {}\n# TYPE {} gauge\n", name, help, name);
+            for (params, values) in &self.rows {
+                // One label per parameter, e.g. `size="100"`.
+                let params: String = params
+                    .iter()
+                    .map(|(name, value)| format!(",{}=\"{}\"", name, escape(&value.to_string())))
+                    .collect();
+                out += &format!(
+                    "{}{{zkvm=\"{}\",workload=\"{}\"{}}} {}\n",
+                    name,
+                    escape(&self.zkvm),
+                    escape(&self.workload),
+                    params,
+                    values[i]
+                );
+            }
+        }
+        out += "# EOF\n";
+        out
+    }
+}
+
+/// Writes `content` to a temporary file next to `path` and renames it over `path`, so that a
+/// reader never sees a partially written file.
+pub fn write_atomic(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".tmp");
+    let tmp_path = path.with_file_name(tmp_name);
+
+    let mut file = File::create(&tmp_path)?;
+    file.write_all(content)?;
+    file.sync_all()?;
+    std::fs::rename(&tmp_path, path)
+}
+
+fn escape(label: &str) -> String {
+    label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,270 @@
+use serde::{Serialize, Serializer};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Name of the parameter of the hosts that take a single integer, e.g. the Fibonacci term or the
+/// number of bytes to hash.
+pub const SIZE: &str = "size";
+
+/// Value of a benchmark parameter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamValue {
+    Int(u64),
+    Float(f64),
+    Str(String),
+}
+
+impl ParamValue {
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            ParamValue::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            ParamValue::Int(value) => Some(*value as f64),
+            ParamValue::Float(value) => Some(*value),
+            ParamValue::Str(_) => None,
+        }
+    }
+}
+
+impl Display for ParamValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamValue::Int(value) => write!(f, "{}", value),
+            ParamValue::Float(value) => write!(f, "{}", value),
+            ParamValue::Str(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl Serialize for ParamValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ParamValue::Int(value) => serializer.serialize_u64(*value),
+            ParamValue::Float(value) => serializer.serialize_f64(*value),
+            ParamValue::Str(value) => serializer.serialize_str(value),
+        }
+    }
+}
+
+/// Parses integers, then floats, and keeps anything else as a string.
+impl FromStr for ParamValue {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(if let Ok(value) = s.parse() {
+            ParamValue::Int(value)
+        } else if let Ok(value) = s.parse() {
+            ParamValue::Float(value)
+        } else {
+            ParamValue::Str(s.to_string())
+        })
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($ty:ty),*) => {
+        $(impl From<$ty> for ParamValue {
+            fn from(value: $ty) -> Self {
+                ParamValue::Int(value as u64)
+            }
+        })*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, usize);
+
+impl From<f64> for ParamValue {
+    fn from(value: f64) -> Self {
+        ParamValue::Float(value)
+    }
+}
+
+impl From<&str> for ParamValue {
+    fn from(value: &str) -> Self {
+        ParamValue::Str(value.to_string())
+    }
+}
+
+impl From<String> for ParamValue {
+    fn from(value: String) -> Self {
+        ParamValue::Str(value)
+    }
+}
+
+/// Named parameters of a benchmark run, e.g. `bytes=32+iterations=10`. Each parameter is written
+/// as its own column of the results, in insertion order.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Params(Vec<(String, ParamValue)>);
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a parameter, replacing its previous value. Names are used as CSV columns and
+    /// OpenMetrics labels, so they are restricted to ASCII letters, digits and underscores.
+    pub fn with(mut self, name: &str, value: impl Into<ParamValue>) -> Self {
+        assert!(
+            !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'),
+            "Invalid parameter name: {}",
+            name
+        );
+        let value = value.into();
+        match self.0.iter_mut().find(|(key, _)| key == name) {
+            Some((_, previous)) => *previous = value,
+            None => self.0.push((name.to_string(), value)),
+        }
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ParamValue> {
+        self.0
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns an integer parameter, panicking if it is missing or not an integer.
+    pub fn u64(&self, name: &str) -> u64 {
+        self.get(name)
+            .and_then(ParamValue::as_u64)
+            .unwrap_or_else(|| panic!("Missing integer parameter {} in {}", name, self))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ParamValue)> {
+        self.0.iter().map(|(name, value)| (name.as_str(), value))
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(|(name, _)| name.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the cartesian product of the values of each parameter, varying the last parameter
+    /// fastest.
+    pub fn product(axes: &[(String, Vec<ParamValue>)]) -> Vec<Params> {
+        axes.iter()
+            .fold(vec![Params::new()], |sets, (name, values)| {
+                sets.iter()
+                    .flat_map(|params| {
+                        values
+                            .iter()
+                            .map(|value| params.clone().with(name, value.clone()))
+                    })
+                    .collect()
+            })
+    }
+}
+
+/// A single `size` parameter is displayed as its value alone, so that the file names and run
+/// names of the single-parameter hosts read as before. Other sets are displayed as
+/// `name=value` pairs joined by `+`.
+impl Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let [(name, value)] = self.0.as_slice() {
+            if name == SIZE {
+                return write!(f, "{}", value);
+            }
+        }
+        let pairs: Vec<String> = self
+            .0
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+        write!(f, "{}", pairs.join("+"))
+    }
+}
+
+/// Parses the output of [`Display`]: `name=value` pairs joined by `+`, where a bare value is the
+/// `size` parameter.
+impl FromStr for Params {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::new();
+        for pair in s.split('+').map(str::trim) {
+            let (name, value) = pair.split_once('=').unwrap_or((SIZE, pair));
+            if value.is_empty() {
+                return Err(format!("Missing value for parameter {}", name));
+            }
+            params = params.with(name.trim(), value.trim().parse::<ParamValue>().unwrap());
+        }
+        Ok(params)
+    }
+}
+
+/// Input of a benchmark function, converted to and from the parameters recorded with its results.
+///
+/// The integer types are a single `size` parameter, which is what most hosts take. Hosts with
+/// several parameters take [`Params`] directly.
+pub trait ParamSet: Sized {
+    fn to_params(&self) -> Params;
+
+    /// Returns `None` if `params` do not describe an input of this type.
+    fn from_params(params: &Params) -> Option<Self>;
+}
+
+/// Any set of parameters. Which names a host accepts is given by its default inputs, see
+/// [`crate::bench::benchmark`].
+impl ParamSet for Params {
+    fn to_params(&self) -> Params {
+        self.clone()
+    }
+
+    fn from_params(params: &Params) -> Option<Self> {
+        Some(params.clone())
+    }
+}
+
+macro_rules! impl_param_set_int {
+    ($($ty:ty),*) => {
+        $(impl ParamSet for $ty {
+            fn to_params(&self) -> Params {
+                Params::new().with(SIZE, *self)
+            }
+
+            fn from_params(params: &Params) -> Option<Self> {
+                match params.0.as_slice() {
+                    [(name, value)] if name == SIZE => value.as_u64()?.try_into().ok(),
+                    _ => None,
+                }
+            }
+        })*
+    };
+}
+
+impl_param_set_int!(u32, u64, usize);
+
+/// Parses the `--param <name>=<value>,<value>...` arguments into the cartesian product of their
+/// values, e.g. `--param bytes=32,64 --param iterations=1,10` gives four parameter sets. Returns
+/// `None` when there is no such argument.
+pub fn from_args(args: &[String]) -> Option<Vec<Params>> {
+    let axes: Vec<(String, Vec<ParamValue>)> = args
+        .iter()
+        .zip(args.iter().skip(1))
+        .filter(|(flag, _)| *flag == "--param")
+        .map(|(_, axis)| {
+            let (name, values) = axis
+                .split_once('=')
+                .unwrap_or_else(|| panic!("Invalid --param {}, expected <name>=<values>", axis));
+            let values = values
+                .split(',')
+                .map(|value| value.trim().parse().unwrap())
+                .collect();
+            (name.trim().to_string(), values)
+        })
+        .collect();
+    (!axes.is_empty()).then(|| Params::product(&axes))
+}
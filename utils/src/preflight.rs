@@ -0,0 +1,275 @@
+use serde::Serialize;
+use std::path::Path;
+
+use crate::sysfs::Sysfs;
+
+/// `off`, `warn` (default) or `strict`: whether to skip the preflight, warn when the machine is
+/// outside the bounds below, or refuse to run.
+pub const PREFLIGHT_ENV: &str = "BENCH_PREFLIGHT";
+/// Expected cpufreq governor, `performance` by default.
+pub const GOVERNOR_ENV: &str = "BENCH_GOVERNOR";
+/// Expected turbo/boost state, `on` or `off`. Not checked by default.
+pub const TURBO_ENV: &str = "BENCH_TURBO";
+/// Maximum 1-minute load average, 1.0 by default.
+pub const MAX_LOAD_ENV: &str = "BENCH_MAX_LOAD";
+/// Minimum available memory, in GiB. Not checked by default.
+pub const MIN_MEMORY_ENV: &str = "BENCH_MIN_AVAILABLE_MEMORY_GB";
+/// Cores to pin the benchmark process to, e.g. `0-3,6`. Linux only.
+pub const CPUS_ENV: &str = "BENCH_CPUS";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PreflightMode {
+    Off,
+    #[default]
+    Warn,
+    Strict,
+}
+
+/// Bounds the machine should be within to run benchmarks, read from the environment.
+#[derive(Debug, Clone)]
+pub struct PreflightConfig {
+    pub mode: PreflightMode,
+    pub governor: Option<String>,
+    pub turbo: Option<bool>,
+    pub max_load: Option<f64>,
+    pub min_available_memory: Option<u64>,
+    pub cpus: Option<Vec<usize>>,
+}
+
+impl PreflightConfig {
+    pub fn from_env() -> Self {
+        let var = |name: &str| std::env::var(name).ok();
+        PreflightConfig {
+            mode: match var(PREFLIGHT_ENV).as_deref() {
+                None | Some("warn") => PreflightMode::Warn,
+                Some("off") => PreflightMode::Off,
+                Some("strict") => PreflightMode::Strict,
+                Some(mode) => panic!("Invalid {}: {}", PREFLIGHT_ENV, mode),
+            },
+            governor: Some(var(GOVERNOR_ENV).unwrap_or("performance".to_string())),
+            turbo: var(TURBO_ENV).map(|turbo| match turbo.as_str() {
+                "on" => true,
+                "off" => false,
+                _ => panic!("Invalid {}: {}, expected on or off", TURBO_ENV, turbo),
+            }),
+            max_load: Some(
+                var(MAX_LOAD_ENV)
+                    .map(|load| load.parse().expect("Invalid maximum load average"))
+                    .unwrap_or(1.0),
+            ),
+            min_available_memory: var(MIN_MEMORY_ENV).map(|gb| {
+                let gb: f64 = gb.parse().expect("Invalid minimum available memory");
+                (gb * (1u64 << 30) as f64) as u64
+            }),
+            cpus: var(CPUS_ENV).map(|cpus| parse_cpu_list(&cpus).expect("Invalid CPU list")),
+        }
+    }
+}
+
+/// State of the machine before a run, recorded with its results.
+#[derive(Debug, Clone, Serialize)]
+pub struct PreflightReport {
+    pub mode: PreflightMode,
+    /// Distinct cpufreq governors of the CPUs.
+    pub governors: Vec<String>,
+    pub turbo: Option<bool>,
+    pub load_average: Option<f64>,
+    pub available_memory: Option<u64>,
+    pub pinned_cpus: Option<Vec<usize>>,
+    /// Bounds the machine was outside of.
+    pub violations: Vec<String>,
+}
+
+impl PreflightReport {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Failed to serialize preflight")
+    }
+
+    pub fn write(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::write(path, self.to_json())
+    }
+}
+
+/// Checks the machine against `config`, and pins the process to the configured cores: the
+/// threads already running, e.g. a prover pool created by the host, and those spawned afterwards,
+/// which inherit the affinity of their parent.
+///
+/// Outside the bounds, warns, or panics in strict mode.
+pub fn run(config: &PreflightConfig) -> PreflightReport {
+    let sysfs = Sysfs::from_env();
+    let mut report = PreflightReport {
+        mode: config.mode,
+        governors: governors(&sysfs),
+        turbo: turbo(&sysfs),
+        load_average: load_average(),
+        available_memory: available_memory(),
+        pinned_cpus: None,
+        violations: Vec::new(),
+    };
+    if config.mode == PreflightMode::Off {
+        return report;
+    }
+
+    if let Some(cpus) = &config.cpus {
+        match pin(cpus) {
+            Ok(()) => report.pinned_cpus = Some(cpus.clone()),
+            Err(err) => report
+                .violations
+                .push(format!("could not pin to cores {:?}: {}", cpus, err)),
+        }
+    }
+    if let Some(expected) = &config.governor {
+        if let Some(governor) = report
+            .governors
+            .iter()
+            .find(|governor| *governor != expected)
+        {
+            report.violations.push(format!(
+                "cpufreq governor is {}, expected {}",
+                governor, expected
+            ));
+        }
+    }
+    if let (Some(expected), Some(turbo)) = (config.turbo, report.turbo) {
+        if expected != turbo {
+            report.violations.push(format!(
+                "turbo is {}, expected {}",
+                on_off(turbo),
+                on_off(expected)
+            ));
+        }
+    }
+    if let (Some(max), Some(load)) = (config.max_load, report.load_average) {
+        if load > max {
+            report
+                .violations
+                .push(format!("load average is {:.2}, above {:.2}", load, max));
+        }
+    }
+    if let (Some(min), Some(available)) = (config.min_available_memory, report.available_memory) {
+        if available < min {
+            report.violations.push(format!(
+                "available memory is {:.1} GiB, below {:.1} GiB",
+                available as f64 / (1u64 << 30) as f64,
+                min as f64 / (1u64 << 30) as f64
+            ));
+        }
+    }
+
+    if !report.violations.is_empty() {
+        let message = format!(
+            "Machine is not stable enough to benchmark:\n  - {}",
+            report.violations.join("\n  - ")
+        );
+        match config.mode {
+            PreflightMode::Strict => {
+                panic!("{}\nSet {}=warn to run anyway.", message, PREFLIGHT_ENV)
+            }
+            _ => eprintln!("Warning: {}", message),
+        }
+    }
+
+    report
+}
+
+fn governors(sysfs: &Sysfs) -> Vec<String> {
+    let mut governors: Vec<String> = sysfs
+        .cpus()
+        .iter()
+        .filter_map(|cpu| sysfs.read(cpu.join("cpufreq/scaling_governor")))
+        .collect();
+    governors.sort();
+    governors.dedup();
+    governors
+}
+
+fn turbo(sysfs: &Sysfs) -> Option<bool> {
+    let read = |path: &str| {
+        sysfs
+            .read(Path::new("devices/system/cpu").join(path))
+            .map(|value| value == "1")
+    };
+    // intel_pstate exposes the opposite flag.
+    read("intel_pstate/no_turbo")
+        .map(|no_turbo| !no_turbo)
+        .or_else(|| read("cpufreq/boost"))
+}
+
+fn load_average() -> Option<f64> {
+    let mut loads = [0f64; 3];
+    let res = unsafe { libc::getloadavg(loads.as_mut_ptr(), 3) };
+    (res > 0).then_some(loads[0])
+}
+
+fn available_memory() -> Option<u64> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    let kb: u64 = meminfo
+        .lines()
+        .find_map(|line| line.strip_prefix("MemAvailable:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kb * 1024)
+}
+
+/// Sets the affinity of every thread of the process, as `sched_setaffinity` only applies to a
+/// single thread.
+#[cfg(target_os = "linux")]
+fn pin(cpus: &[usize]) -> std::io::Result<()> {
+    let set_size = libc::CPU_SETSIZE as usize;
+    let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    for &cpu in cpus {
+        if cpu >= set_size {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("core {} is beyond the {} cores of a CPU set", cpu, set_size),
+            ));
+        }
+        unsafe { libc::CPU_SET(cpu, &mut set) };
+    }
+
+    for task in std::fs::read_dir("/proc/self/task")? {
+        let Some(tid) = task?.file_name().to_str().and_then(|tid| tid.parse().ok()) else {
+            continue;
+        };
+        let res =
+            unsafe { libc::sched_setaffinity(tid, std::mem::size_of::<libc::cpu_set_t>(), &set) };
+        let err = std::io::Error::last_os_error();
+        // A thread may have exited since the directory was listed.
+        if res != 0 && err.raw_os_error() != Some(libc::ESRCH) {
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn pin(_cpus: &[usize]) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "core pinning is only supported on Linux",
+    ))
+}
+
+/// Parses a CPU list such as `0-3,6`.
+fn parse_cpu_list(list: &str) -> Option<Vec<usize>> {
+    let mut cpus = Vec::new();
+    for range in list.split(',').map(str::trim) {
+        match range.split_once('-') {
+            Some((start, end)) => cpus.extend(start.parse::<usize>().ok()?..=end.parse().ok()?),
+            None => cpus.push(range.parse().ok()?),
+        }
+    }
+    Some(cpus)
+}
+
+fn on_off(value: bool) -> &'static str {
+    if value {
+        "on"
+    } else {
+        "off"
+    }
+}
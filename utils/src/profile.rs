@@ -2,23 +2,193 @@ use pprof::protos::Message;
 use std::any::Any;
 use std::fs::File;
 use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Mutex, OnceLock};
 use tracing_chrome::ChromeLayerBuilder;
 use tracing_subscriber::{self, prelude::*};
 
-pub fn profile_func<F>(func: F, file_path: &str) -> Result<(), Box<dyn std::error::Error>>
+use crate::{heap, rusage, timeline};
+
+const DEFAULT_FREQUENCY: i32 = 1000;
+const DEFAULT_BLOCKLIST: [&str; 6] = ["libc", "libgcc", "pthread", "vdso", "rayon::", "std::"];
+const DEFAULT_OUTPUT_DIR: &str = "../.outputs/profiling";
+
+/// Phase of a benchmark run that can be profiled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// SDK or prover client initialization.
+    Init,
+    /// Loading, compiling or transpiling the guest program.
+    Load,
+    Execute,
+    Setup,
+    Prove,
+    Verify,
+}
+
+impl Phase {
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Phase::Init => "init",
+            Phase::Load => "load",
+            Phase::Execute => "execute",
+            Phase::Setup => "setup",
+            Phase::Prove => "prove",
+            Phase::Verify => "verify",
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "init" => Ok(Phase::Init),
+            "load" => Ok(Phase::Load),
+            "execute" => Ok(Phase::Execute),
+            "setup" => Ok(Phase::Setup),
+            "prove" => Ok(Phase::Prove),
+            "verify" => Ok(Phase::Verify),
+            _ => Err(format!(
+                "Unknown phase '{}', expected init, load, execute, setup, prove or verify",
+                s
+            )),
+        }
+    }
+}
+
+/// Profiling options, read from the command line of the host binary:
+///
+/// * `--profile <phase>` - Phase to profile: `init`, `load`, `execute`, `setup`, `prove` or
+///   `verify`.
+/// * `--profile-frequency <hz>` - Sampling frequency, 1000 Hz by default.
+/// * `--profile-blocklist <a,b,..>` - Library prefixes whose frames are skipped.
+/// * `--profile-dir <dir>` - Output directory, `.outputs/profiling` by default.
+#[derive(Debug, Clone)]
+pub struct ProfileConfig {
+    pub phase: Phase,
+    pub frequency: i32,
+    pub blocklist: Vec<String>,
+    pub output_dir: PathBuf,
+}
+
+impl ProfileConfig {
+    /// Returns the profiling options if `--profile` was given.
+    pub fn from_args(args: &[String]) -> Option<Self> {
+        let value = |flag: &str| args.iter().skip_while(|arg| *arg != flag).nth(1);
+
+        let phase = value("--profile")?
+            .parse()
+            .unwrap_or_else(|err| panic!("{}", err));
+        let mut config = ProfileConfig {
+            phase,
+            ..Default::default()
+        };
+        if let Some(frequency) = value("--profile-frequency") {
+            config.frequency = frequency
+                .parse()
+                .expect("--profile-frequency should be an integer");
+        }
+        if let Some(list) = value("--profile-blocklist") {
+            config.blocklist = list.split(',').map(str::to_string).collect();
+        }
+        if let Some(dir) = value("--profile-dir") {
+            config.output_dir = PathBuf::from(dir);
+        }
+
+        Some(config)
+    }
+}
+
+impl Default for ProfileConfig {
+    fn default() -> Self {
+        ProfileConfig {
+            phase: Phase::Prove,
+            frequency: DEFAULT_FREQUENCY,
+            blocklist: DEFAULT_BLOCKLIST
+                .iter()
+                .map(|lib| lib.to_string())
+                .collect(),
+            output_dir: PathBuf::from(DEFAULT_OUTPUT_DIR),
+        }
+    }
+}
+
+fn config() -> Option<&'static ProfileConfig> {
+    static CONFIG: OnceLock<Option<ProfileConfig>> = OnceLock::new();
+    CONFIG
+        .get_or_init(|| ProfileConfig::from_args(&std::env::args().collect::<Vec<_>>()))
+        .as_ref()
+}
+
+/// Returns whether a phase of each run is profiled, as selected with `--profile`.
+pub fn enabled() -> bool {
+    config().is_some()
+}
+
+/// Name of the run being benchmarked, used to name the profile files.
+static RUN_NAME: Mutex<Option<String>> = Mutex::new(None);
+
+/// Sets the name of the run being benchmarked, e.g. `fib_sp1_1000_0` for the first repetition.
+pub fn set_run_name(name: &str) {
+    *RUN_NAME.lock().unwrap() = Some(name.to_string());
+}
+
+/// Runs one phase of a benchmark, profiling it if it is the phase selected with `--profile`.
+///
+/// The profile is written to `<run>_<phase>.pb` and `<run>_<phase>.svg` in the profiling output
+/// directory. Every phase also records its OS counters (see [`crate::rusage`]), its heap usage
+/// when the counting allocator is installed (see [`crate::heap`]), and its span on the memory
+/// timeline when one is kept (see [`crate::timeline`]).
+pub fn phase<R, F: FnOnce() -> R>(phase: Phase, func: F) -> R {
+    let func = || {
+        timeline::mark_phase(phase, || {
+            heap::measure_phase(phase, || rusage::measure_phase(phase, func))
+        })
+    };
+    let Some(config) = config().filter(|config| config.phase == phase) else {
+        return func();
+    };
+
+    let run = RUN_NAME
+        .lock()
+        .unwrap()
+        .clone()
+        .unwrap_or("profile".to_string());
+    let path = config
+        .output_dir
+        .join(format!("{}_{}.pb", run, phase.name()));
+
+    profile_func(func, &path, config).expect("Failed to write profile")
+}
+
+/// Profiles `func` and writes the pprof protobuf to `pb_path`, and a flamegraph next to it with
+/// an `.svg` extension.
+pub fn profile_func<R, F>(
+    func: F,
+    pb_path: &Path,
+    config: &ProfileConfig,
+) -> Result<R, Box<dyn std::error::Error>>
 where
-    F: FnOnce(),
+    F: FnOnce() -> R,
 {
+    let blocklist: Vec<&str> = config.blocklist.iter().map(String::as_str).collect();
     let guard = pprof::ProfilerGuardBuilder::default()
-        .frequency(1000)
-        .blocklist(&["libc", "libgcc", "pthread", "vdso", "rayon::", "std::"])
+        .frequency(config.frequency)
+        .blocklist(&blocklist)
         .build()
         .unwrap();
 
-    func();
+    let result = func();
 
     if let Ok(report) = guard.report().build() {
-        let mut file = File::create(file_path)?;
+        if let Some(dir) = pb_path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let mut file = File::create(pb_path)?;
         let profile = report.pprof().unwrap();
 
         // Serialize the profile to protobuf format
@@ -26,10 +196,17 @@ where
         profile.encode(&mut content)?;
         file.write_all(&content)?;
 
-        println!("Profile data written to {}", file_path);
+        let svg_path = pb_path.with_extension("svg");
+        report.flamegraph(File::create(&svg_path)?)?;
+
+        println!(
+            "Profile data written to {} and {}",
+            pb_path.display(),
+            svg_path.display()
+        );
     };
 
-    Ok(())
+    Ok(result)
 }
 
 pub fn init_trace() {
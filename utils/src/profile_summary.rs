@@ -0,0 +1,325 @@
+use pprof::protos::{Message, Profile};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+use std::path::Path;
+
+/// Crate of the frames whose name is not a Rust path, e.g. libc symbols.
+const NATIVE_CRATE: &str = "[native]";
+
+/// Time spent in one function.
+#[derive(Debug, Clone, Default)]
+pub struct FunctionTime {
+    pub name: String,
+    pub krate: String,
+    /// Nanoseconds sampled with the function at the top of the stack.
+    pub self_nanos: i64,
+    /// Nanoseconds sampled with the function anywhere in the stack.
+    pub inclusive_nanos: i64,
+}
+
+/// Self and inclusive time per function and per crate of a pprof profile, as written by
+/// [`crate::profile::profile_func`].
+#[derive(Debug, Clone, Default)]
+pub struct ProfileSummary {
+    pub total_nanos: i64,
+    pub functions: BTreeMap<String, FunctionTime>,
+    pub crates: BTreeMap<String, FunctionTime>,
+}
+
+impl ProfileSummary {
+    pub fn from_file(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = std::fs::read(path)?;
+        Ok(Self::from_profile(&Profile::decode(content.as_slice())?))
+    }
+
+    pub fn from_profile(profile: &Profile) -> Self {
+        let string = |index: i64| {
+            profile
+                .string_table
+                .get(index as usize)
+                .map_or("", String::as_str)
+        };
+        let functions: BTreeMap<u64, String> = profile
+            .function
+            .iter()
+            .map(|function| (function.id, strip_hash(string(function.name)).to_string()))
+            .collect();
+        // Frames of each location, innermost (inlined) first.
+        let locations: BTreeMap<u64, Vec<&String>> = profile
+            .location
+            .iter()
+            .map(|location| {
+                let frames = location
+                    .line
+                    .iter()
+                    .filter_map(|line| functions.get(&line.function_id))
+                    .collect();
+                (location.id, frames)
+            })
+            .collect();
+        // pprof-rs records the sample count and the CPU time, the time is the last value.
+        let time_index = profile.sample_type.len().saturating_sub(1);
+
+        let mut summary = ProfileSummary::default();
+        for sample in &profile.sample {
+            let nanos = match sample.value.get(time_index) {
+                Some(value) if profile.sample_type.len() > 1 => *value,
+                Some(count) => count * profile.period,
+                None => continue,
+            };
+            summary.total_nanos += nanos;
+
+            // Stacks are stored leaf first.
+            let stack: Vec<&String> = sample
+                .location_id
+                .iter()
+                .filter_map(|id| locations.get(id))
+                .flatten()
+                .copied()
+                .collect();
+            if let Some(leaf) = stack.first() {
+                summary.function(leaf).self_nanos += nanos;
+                summary.krate(leaf).self_nanos += nanos;
+            }
+            // Recursive frames are only counted once per sample.
+            let mut seen_functions = HashSet::new();
+            let mut seen_crates = HashSet::new();
+            for name in stack {
+                if seen_functions.insert(name) {
+                    summary.function(name).inclusive_nanos += nanos;
+                }
+                if seen_crates.insert(crate_name(name)) {
+                    summary.krate(name).inclusive_nanos += nanos;
+                }
+            }
+        }
+
+        summary
+    }
+
+    fn function(&mut self, name: &str) -> &mut FunctionTime {
+        self.functions
+            .entry(name.to_string())
+            .or_insert_with(|| FunctionTime {
+                name: name.to_string(),
+                krate: crate_name(name).to_string(),
+                ..Default::default()
+            })
+    }
+
+    fn krate(&mut self, name: &str) -> &mut FunctionTime {
+        let krate = crate_name(name);
+        self.crates
+            .entry(krate.to_string())
+            .or_insert_with(|| FunctionTime {
+                name: krate.to_string(),
+                krate: krate.to_string(),
+                ..Default::default()
+            })
+    }
+
+    /// Returns the `n` functions with the most self time, or inclusive time.
+    pub fn top(&self, n: usize, inclusive: bool) -> Vec<&FunctionTime> {
+        let mut functions: Vec<&FunctionTime> = self.functions.values().collect();
+        functions.sort_by_key(|function| {
+            std::cmp::Reverse(if inclusive {
+                function.inclusive_nanos
+            } else {
+                function.self_nanos
+            })
+        });
+        functions.truncate(n);
+        functions
+    }
+
+    /// Renders the time per crate and the top `n` functions by self and inclusive time.
+    pub fn to_markdown(&self, title: &str, n: usize) -> String {
+        let mut out = String::new();
+        writeln!(out, "# {}\n", title).unwrap();
+        writeln!(out, "Total sampled time: {}\n", format_ms(self.total_nanos)).unwrap();
+
+        writeln!(out, "## Time by crate\n").unwrap();
+        writeln!(out, "| Crate | Self | Self % | Inclusive | Inclusive % |").unwrap();
+        writeln!(out, "| --- | ---: | ---: | ---: | ---: |").unwrap();
+        let mut crates: Vec<&FunctionTime> = self.crates.values().collect();
+        crates.sort_by_key(|krate| std::cmp::Reverse(krate.self_nanos));
+        for krate in crates {
+            writeln!(
+                out,
+                "| `{}` | {} | {} | {} | {} |",
+                krate.name,
+                format_ms(krate.self_nanos),
+                self.percent(krate.self_nanos),
+                format_ms(krate.inclusive_nanos),
+                self.percent(krate.inclusive_nanos)
+            )
+            .unwrap();
+        }
+
+        for (heading, inclusive) in [("self", false), ("inclusive", true)] {
+            writeln!(out, "\n## Top {} functions by {} time\n", n, heading).unwrap();
+            writeln!(
+                out,
+                "| # | Crate | Function | Self | Self % | Inclusive | Inclusive % |"
+            )
+            .unwrap();
+            writeln!(out, "| ---: | --- | --- | ---: | ---: | ---: | ---: |").unwrap();
+            for (rank, function) in self.top(n, inclusive).iter().enumerate() {
+                writeln!(
+                    out,
+                    "| {} | `{}` | `{}` | {} | {} | {} | {} |",
+                    rank + 1,
+                    function.krate,
+                    escape(&function.name),
+                    format_ms(function.self_nanos),
+                    self.percent(function.self_nanos),
+                    format_ms(function.inclusive_nanos),
+                    self.percent(function.inclusive_nanos)
+                )
+                .unwrap();
+            }
+        }
+
+        out
+    }
+
+    fn percent(&self, nanos: i64) -> String {
+        if self.total_nanos == 0 {
+            return "-".to_string();
+        }
+        format!("{:.2}%", nanos as f64 * 100.0 / self.total_nanos as f64)
+    }
+}
+
+/// Renders the functions and crates whose time changed the most between two profiles of the same
+/// zkVM, e.g. before and after an SDK upgrade.
+pub fn diff_markdown(
+    before: &ProfileSummary,
+    after: &ProfileSummary,
+    title: &str,
+    n: usize,
+) -> String {
+    let mut out = String::new();
+    writeln!(out, "# {}\n", title).unwrap();
+    writeln!(
+        out,
+        "Total sampled time: {} -> {} ({})\n",
+        format_ms(before.total_nanos),
+        format_ms(after.total_nanos),
+        format_delta(before.total_nanos, after.total_nanos)
+    )
+    .unwrap();
+
+    writeln!(out, "## Self time by crate\n").unwrap();
+    writeln!(out, "| Crate | Before | After | Change |").unwrap();
+    writeln!(out, "| --- | ---: | ---: | ---: |").unwrap();
+    for (name, _, before, after) in diff_rows(&before.crates, &after.crates, usize::MAX, false) {
+        writeln!(
+            out,
+            "| `{}` | {} | {} | {} |",
+            name,
+            format_ms(before),
+            format_ms(after),
+            format_delta(before, after)
+        )
+        .unwrap();
+    }
+
+    for (heading, inclusive) in [("self", false), ("inclusive", true)] {
+        writeln!(out, "\n## Top {} changes in {} time\n", n, heading).unwrap();
+        writeln!(out, "| Crate | Function | Before | After | Change |").unwrap();
+        writeln!(out, "| --- | --- | ---: | ---: | ---: |").unwrap();
+        for (name, krate, before, after) in
+            diff_rows(&before.functions, &after.functions, n, inclusive)
+        {
+            writeln!(
+                out,
+                "| `{}` | `{}` | {} | {} | {} |",
+                krate,
+                escape(name),
+                format_ms(before),
+                format_ms(after),
+                format_delta(before, after)
+            )
+            .unwrap();
+        }
+    }
+
+    out
+}
+
+/// Returns the `n` entries whose time changed the most, as (name, crate, before, after).
+fn diff_rows<'a>(
+    before: &'a BTreeMap<String, FunctionTime>,
+    after: &'a BTreeMap<String, FunctionTime>,
+    n: usize,
+    inclusive: bool,
+) -> Vec<(&'a String, &'a String, i64, i64)> {
+    let time = |times: &BTreeMap<String, FunctionTime>, name: &str| {
+        times.get(name).map_or(0, |time| {
+            if inclusive {
+                time.inclusive_nanos
+            } else {
+                time.self_nanos
+            }
+        })
+    };
+
+    let mut rows: Vec<(&String, &String, i64, i64)> = before
+        .iter()
+        .chain(after.iter())
+        .map(|(name, function)| (name, &function.krate))
+        .collect::<BTreeMap<_, _>>()
+        .into_iter()
+        .map(|(name, krate)| (name, krate, time(before, name), time(after, name)))
+        .filter(|(_, _, before, after)| before != after)
+        .collect();
+    rows.sort_by_key(|(_, _, before, after)| std::cmp::Reverse((after - before).abs()));
+    rows.truncate(n);
+
+    rows
+}
+
+/// Returns the crate of a demangled function name, e.g. `sp1_core_machine` for
+/// `<sp1_core_machine::cpu::CpuChip as p3_air::Air<AB>>::eval`.
+pub fn crate_name(function: &str) -> &str {
+    let path = function
+        .trim_start_matches(['<', '&', '*'])
+        .trim_start_matches("mut ")
+        .trim_start_matches("const ")
+        .trim_start_matches("dyn ");
+    match path.find("::") {
+        Some(end) if !path[..end].contains([' ', '<', '(']) => &path[..end],
+        _ => NATIVE_CRATE,
+    }
+}
+
+/// Removes the `::h0123456789abcdef` hash suffix of legacy mangled names.
+fn strip_hash(name: &str) -> &str {
+    match name.rsplit_once("::h") {
+        Some((path, hash)) if hash.len() == 16 && hash.chars().all(|c| c.is_ascii_hexdigit()) => {
+            path
+        }
+        _ => name,
+    }
+}
+
+fn escape(name: &str) -> String {
+    name.replace('|', "\\|").replace('`', "'")
+}
+
+fn format_ms(nanos: i64) -> String {
+    format!("{:.1} ms", nanos as f64 / 1e6)
+}
+
+fn format_delta(before: i64, after: i64) -> String {
+    let delta = format!("{:+.1} ms", (after - before) as f64 / 1e6);
+    if before == 0 {
+        return format!("{} (new)", delta);
+    }
+    format!(
+        "{} ({:+.1}%)",
+        delta,
+        (after - before) as f64 * 100.0 / before as f64
+    )
+}
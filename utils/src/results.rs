@@ -0,0 +1,398 @@
+use rusqlite::{params, Connection};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::bench::{self, Metrics};
+use crate::suite::DEVICE_ENV;
+
+/// Overrides the location of the results database.
+pub const DB_ENV: &str = "BENCH_DB";
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS runs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    zkvm TEXT NOT NULL,
+    workload TEXT NOT NULL,
+    started_at TEXT NOT NULL DEFAULT (datetime('now')),
+    git_commit TEXT,
+    os TEXT NOT NULL,
+    arch TEXT NOT NULL,
+    cpus INTEGER NOT NULL,
+    hostname TEXT,
+    device TEXT
+);
+CREATE TABLE IF NOT EXISTS measurements (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    run_id INTEGER NOT NULL REFERENCES runs(id),
+    params TEXT NOT NULL,
+    status TEXT NOT NULL,
+    metrics TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS artifacts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    run_id INTEGER NOT NULL REFERENCES runs(id),
+    kind TEXT NOT NULL,
+    path TEXT NOT NULL,
+    content BLOB
+);
+";
+
+/// A benchmark invocation: one workload on one zkVM, with the environment it ran in.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunRecord {
+    pub id: i64,
+    pub zkvm: String,
+    pub workload: String,
+    pub started_at: String,
+    pub git_commit: Option<String>,
+    pub os: String,
+    pub arch: String,
+    pub cpus: i64,
+    pub hostname: Option<String>,
+    pub device: Option<String>,
+}
+
+/// A single row of a run. `metrics` holds the serialized [`Metrics`] and its parameters, so that
+/// new columns do not require a schema migration.
+#[derive(Debug, Clone, Serialize)]
+pub struct MeasurementRecord {
+    pub run_id: i64,
+    pub zkvm: String,
+    pub workload: String,
+    pub started_at: String,
+    pub git_commit: Option<String>,
+    /// Parameters of the row, displayed as in the result file names, e.g. `100` or
+    /// `bytes=32+iterations=10`.
+    pub params: String,
+    pub status: String,
+    pub metrics: Value,
+}
+
+/// A file produced by a run, e.g. its results CSV.
+#[derive(Debug, Clone)]
+pub struct ArtifactRecord {
+    pub kind: String,
+    pub path: String,
+    /// Contents of the file when it was recorded, if it could be read.
+    pub content: Option<Vec<u8>>,
+}
+
+/// Restricts queries to a subset of the runs. Unset fields match everything.
+#[derive(Debug, Default, Clone)]
+pub struct RunFilter {
+    pub zkvm: Option<String>,
+    pub workload: Option<String>,
+    pub run_id: Option<i64>,
+}
+
+/// Historical benchmark results, stored in a local SQLite database.
+pub struct ResultsStore {
+    conn: Connection,
+}
+
+impl ResultsStore {
+    pub fn open(path: &Path) -> rusqlite::Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// Opens the database at `$BENCH_DB`, defaulting to `.outputs/results.db`.
+    pub fn open_default() -> rusqlite::Result<Self> {
+        Self::open(&default_path())
+    }
+
+    /// Records a new run, capturing the current environment.
+    pub fn start_run(&self, zkvm: &str, workload: &str) -> rusqlite::Result<i64> {
+        self.conn.execute(
+            "INSERT INTO runs (zkvm, workload, git_commit, os, arch, cpus, hostname, device)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                zkvm,
+                workload,
+                git_commit(),
+                std::env::consts::OS,
+                std::env::consts::ARCH,
+                std::thread::available_parallelism().map_or(1, |n| n.get()) as i64,
+                hostname(),
+                std::env::var(DEVICE_ENV).ok(),
+            ],
+        )?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    pub fn add_measurement(&self, run_id: i64, metrics: &Metrics) -> rusqlite::Result<()> {
+        let mut columns = bench::columns(metrics);
+        for (name, value) in metrics.params.iter() {
+            columns.insert(name.to_string(), serde_json::json!(value));
+        }
+        let json = Value::Object(columns);
+        self.conn.execute(
+            "INSERT INTO measurements (run_id, params, status, metrics) VALUES (?1, ?2, ?3, ?4)",
+            params![
+                run_id,
+                metrics.params.to_string(),
+                metrics.status.to_string(),
+                json.to_string()
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Records a file produced by a run, with its contents at the time of the call, as the file
+    /// itself may be overwritten by a later run.
+    pub fn add_artifact(&self, run_id: i64, kind: &str, path: &Path) -> rusqlite::Result<()> {
+        self.insert_artifact(run_id, kind, path, std::fs::read(path).ok().as_deref())
+    }
+
+    /// Records the contents of a file produced by a run, as produced by the run itself rather
+    /// than read back from `path`.
+    pub fn add_artifact_content(
+        &self,
+        run_id: i64,
+        kind: &str,
+        path: &Path,
+        content: &[u8],
+    ) -> rusqlite::Result<()> {
+        self.insert_artifact(run_id, kind, path, Some(content))
+    }
+
+    fn insert_artifact(
+        &self,
+        run_id: i64,
+        kind: &str,
+        path: &Path,
+        content: Option<&[u8]>,
+    ) -> rusqlite::Result<()> {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        self.conn.execute(
+            "INSERT INTO artifacts (run_id, kind, path, content) VALUES (?1, ?2, ?3, ?4)",
+            params![run_id, kind, path.to_string_lossy(), content],
+        )?;
+        Ok(())
+    }
+
+    pub fn runs(&self, filter: &RunFilter) -> rusqlite::Result<Vec<RunRecord>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, zkvm, workload, started_at, git_commit, os, arch, cpus, hostname, device
+             FROM runs
+             WHERE (?1 IS NULL OR zkvm = ?1) AND (?2 IS NULL OR workload = ?2) AND (?3 IS NULL OR id = ?3)
+             ORDER BY id",
+        )?;
+        let rows = stmt.query_map(
+            params![filter.zkvm, filter.workload, filter.run_id],
+            |row| {
+                Ok(RunRecord {
+                    id: row.get(0)?,
+                    zkvm: row.get(1)?,
+                    workload: row.get(2)?,
+                    started_at: row.get(3)?,
+                    git_commit: row.get(4)?,
+                    os: row.get(5)?,
+                    arch: row.get(6)?,
+                    cpus: row.get(7)?,
+                    hostname: row.get(8)?,
+                    device: row.get(9)?,
+                })
+            },
+        )?;
+        rows.collect()
+    }
+
+    /// Returns the measurements of the matching runs, oldest first.
+    pub fn measurements(&self, filter: &RunFilter) -> rusqlite::Result<Vec<MeasurementRecord>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT m.run_id, r.zkvm, r.workload, r.started_at, r.git_commit, m.params, m.status, m.metrics
+             FROM measurements m JOIN runs r ON r.id = m.run_id
+             WHERE (?1 IS NULL OR r.zkvm = ?1) AND (?2 IS NULL OR r.workload = ?2) AND (?3 IS NULL OR r.id = ?3)
+             ORDER BY r.started_at, m.run_id, m.id",
+        )?;
+        let rows = stmt.query_map(
+            params![filter.zkvm, filter.workload, filter.run_id],
+            |row| {
+                let metrics: String = row.get(7)?;
+                Ok(MeasurementRecord {
+                    run_id: row.get(0)?,
+                    zkvm: row.get(1)?,
+                    workload: row.get(2)?,
+                    started_at: row.get(3)?,
+                    git_commit: row.get(4)?,
+                    params: row.get(5)?,
+                    status: row.get(6)?,
+                    metrics: serde_json::from_str(&metrics).unwrap_or(Value::Null),
+                })
+            },
+        )?;
+        rows.collect()
+    }
+
+    /// Returns the `ok` measurements of one zkVM over time, optionally for a single workload.
+    pub fn trend(
+        &self,
+        zkvm: &str,
+        workload: Option<&str>,
+    ) -> rusqlite::Result<Vec<MeasurementRecord>> {
+        let filter = RunFilter {
+            zkvm: Some(zkvm.to_string()),
+            workload: workload.map(str::to_string),
+            run_id: None,
+        };
+        Ok(self
+            .measurements(&filter)?
+            .into_iter()
+            .filter(|record| record.status == "ok")
+            .collect())
+    }
+
+    pub fn artifacts(&self, run_id: i64) -> rusqlite::Result<Vec<ArtifactRecord>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT kind, path, content FROM artifacts WHERE run_id = ?1 ORDER BY id")?;
+        let rows = stmt.query_map(params![run_id], |row| {
+            Ok(ArtifactRecord {
+                kind: row.get(0)?,
+                path: row.get(1)?,
+                content: row.get(2)?,
+            })
+        })?;
+        rows.collect()
+    }
+}
+
+/// Records the rows of one run, warning instead of failing when the database is unavailable, so
+/// that a broken store never costs a benchmark.
+pub(crate) struct RunRecorder {
+    store: ResultsStore,
+    run_id: i64,
+}
+
+impl RunRecorder {
+    pub(crate) fn start(zkvm: &str, workload: &str) -> Option<Self> {
+        let start = || -> rusqlite::Result<Self> {
+            let store = ResultsStore::open_default()?;
+            let run_id = store.start_run(zkvm, workload)?;
+            Ok(Self { store, run_id })
+        };
+        start()
+            .map_err(|err| eprintln!("Results database unavailable: {}", err))
+            .ok()
+    }
+
+    pub(crate) fn record(&self, metrics: &Metrics) {
+        if let Err(err) = self.store.add_measurement(self.run_id, metrics) {
+            eprintln!("Could not record measurement: {}", err);
+        }
+    }
+
+    pub(crate) fn artifact(&self, kind: &str, path: &Path) {
+        if let Err(err) = self.store.add_artifact(self.run_id, kind, path) {
+            eprintln!("Could not record artifact: {}", err);
+        }
+    }
+
+    pub(crate) fn artifact_content(&self, kind: &str, path: &Path, content: &[u8]) {
+        if let Err(err) = self
+            .store
+            .add_artifact_content(self.run_id, kind, path, content)
+        {
+            eprintln!("Could not record artifact: {}", err);
+        }
+    }
+}
+
+const RUN_COLUMNS: [&str; 5] = ["run_id", "zkvm", "workload", "started_at", "git_commit"];
+
+/// Flattens measurements into rows: the run columns followed by the metrics columns.
+pub fn flatten(records: &[MeasurementRecord]) -> Vec<Map<String, Value>> {
+    records
+        .iter()
+        .map(|record| {
+            let mut row = Map::new();
+            row.insert("run_id".into(), record.run_id.into());
+            row.insert("zkvm".into(), record.zkvm.clone().into());
+            row.insert("workload".into(), record.workload.clone().into());
+            row.insert("started_at".into(), record.started_at.clone().into());
+            row.insert(
+                "git_commit".into(),
+                record.git_commit.clone().map_or(Value::Null, Value::from),
+            );
+            if let Value::Object(metrics) = &record.metrics {
+                row.extend(metrics.clone());
+            }
+            row
+        })
+        .collect()
+}
+
+pub fn export_csv(
+    records: &[MeasurementRecord],
+    path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let rows = flatten(records);
+    let mut out = csv::Writer::from_path(path)?;
+
+    let mut headers: Vec<String> = RUN_COLUMNS
+        .iter()
+        .map(|column| column.to_string())
+        .collect();
+    for row in &rows {
+        for key in row.keys() {
+            if !headers.contains(key) {
+                headers.push(key.clone());
+            }
+        }
+    }
+    out.write_record(&headers)?;
+
+    for row in &rows {
+        out.write_record(headers.iter().map(|header| match row.get(header) {
+            None | Some(Value::Null) => String::new(),
+            Some(Value::String(value)) => value.clone(),
+            Some(value) => value.to_string(),
+        }))?;
+    }
+    out.flush()?;
+
+    Ok(())
+}
+
+pub fn export_json(
+    records: &[MeasurementRecord],
+    path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let json = serde_json::to_string_pretty(&flatten(records))?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+pub fn default_path() -> PathBuf {
+    match std::env::var(DB_ENV) {
+        Ok(path) => PathBuf::from(path),
+        Err(_) => Path::new(env!("CARGO_MANIFEST_DIR")).join("../.outputs/results.db"),
+    }
+}
+
+/// Upgrades a database written by an earlier version of the schema.
+fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn hostname() -> Option<String> {
+    let mut buf = [0u8; 256];
+    let res = unsafe { libc::gethostname(buf.as_mut_ptr() as *mut libc::c_char, buf.len()) };
+    if res != 0 {
+        return None;
+    }
+    let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+    Some(String::from_utf8_lossy(&buf[..len]).into_owned())
+}
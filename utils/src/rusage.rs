@@ -0,0 +1,172 @@
+use std::sync::Mutex;
+
+use crate::profile::Phase;
+
+/// OS counters of the phases measured since the last [`take_phase_stats`].
+static PHASES: Mutex<Vec<(Phase, OsCounters)>> = Mutex::new(Vec::new());
+
+/// `getrusage` counters of the threads of the harness, see [`ExcludedThread`].
+static EXCLUDED: Mutex<OsCounters> = Mutex::new(OsCounters {
+    major_faults: 0,
+    minor_faults: 0,
+    voluntary_switches: 0,
+    involuntary_switches: 0,
+    read_bytes: 0,
+    write_bytes: 0,
+    swapped_pages: 0,
+});
+
+/// Resource counters of the process, from `getrusage` and `/proc/self/io`, without the page
+/// faults and context switches of the threads of the harness (see [`ExcludedThread`]).
+///
+/// The I/O and swap counters are only available on Linux, and are zero elsewhere.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OsCounters {
+    pub major_faults: u64,
+    pub minor_faults: u64,
+    pub voluntary_switches: u64,
+    pub involuntary_switches: u64,
+    /// Bytes read from storage.
+    pub read_bytes: u64,
+    /// Bytes written to storage.
+    pub write_bytes: u64,
+    /// Pages swapped in or out by the whole system, as the kernel does not count them per process.
+    pub swapped_pages: u64,
+}
+
+impl OsCounters {
+    /// Returns the counters of the process since it started.
+    pub fn snapshot() -> Self {
+        let usage = getrusage(libc::RUSAGE_SELF);
+        let excluded = *EXCLUDED.lock().unwrap();
+        let io = read_proc_fields("/proc/self/io", ':');
+        let vmstat = read_proc_fields("/proc/vmstat", ' ');
+        let field = |fields: &[(String, u64)], name: &str| {
+            fields
+                .iter()
+                .find(|(key, _)| key == name)
+                .map_or(0, |(_, value)| *value)
+        };
+
+        OsCounters {
+            read_bytes: field(&io, "read_bytes"),
+            write_bytes: field(&io, "write_bytes"),
+            swapped_pages: field(&vmstat, "pswpin") + field(&vmstat, "pswpout"),
+            ..OsCounters::from_rusage(&usage).since(&excluded)
+        }
+    }
+
+    /// Returns the page faults and context switches of `usage`.
+    fn from_rusage(usage: &libc::rusage) -> Self {
+        OsCounters {
+            major_faults: usage.ru_majflt as u64,
+            minor_faults: usage.ru_minflt as u64,
+            voluntary_switches: usage.ru_nvcsw as u64,
+            involuntary_switches: usage.ru_nivcsw as u64,
+            ..OsCounters::default()
+        }
+    }
+
+    /// Returns the counters accumulated between `earlier` and `self`.
+    pub fn since(&self, earlier: &OsCounters) -> Self {
+        OsCounters {
+            major_faults: self.major_faults.saturating_sub(earlier.major_faults),
+            minor_faults: self.minor_faults.saturating_sub(earlier.minor_faults),
+            voluntary_switches: self
+                .voluntary_switches
+                .saturating_sub(earlier.voluntary_switches),
+            involuntary_switches: self
+                .involuntary_switches
+                .saturating_sub(earlier.involuntary_switches),
+            read_bytes: self.read_bytes.saturating_sub(earlier.read_bytes),
+            write_bytes: self.write_bytes.saturating_sub(earlier.write_bytes),
+            swapped_pages: self.swapped_pages.saturating_sub(earlier.swapped_pages),
+        }
+    }
+}
+
+/// A thread of the harness, e.g. the memory monitor, whose page faults and context switches are
+/// left out of [`OsCounters::snapshot`] so that they are not attributed to the prover.
+///
+/// Created by the thread itself, which calls [`ExcludedThread::update`] before each sleep: the
+/// counters of the process then include at most one period of the thread. Linux only, as other
+/// systems cannot report the usage of a single thread.
+pub(crate) struct ExcludedThread {
+    last: OsCounters,
+}
+
+impl ExcludedThread {
+    pub(crate) fn new() -> Self {
+        // The counters of a thread start at zero, so that its creation is excluded as well.
+        ExcludedThread {
+            last: OsCounters::default(),
+        }
+    }
+
+    /// Excludes the counters of the calling thread up to now.
+    pub(crate) fn update(&mut self) {
+        let Some(current) = thread_counters() else {
+            return;
+        };
+        let delta = current.since(&self.last);
+        self.last = current;
+
+        let mut excluded = EXCLUDED.lock().unwrap();
+        excluded.major_faults += delta.major_faults;
+        excluded.minor_faults += delta.minor_faults;
+        excluded.voluntary_switches += delta.voluntary_switches;
+        excluded.involuntary_switches += delta.involuntary_switches;
+    }
+}
+
+/// Returns the page faults and context switches of the calling thread.
+#[cfg(target_os = "linux")]
+fn thread_counters() -> Option<OsCounters> {
+    Some(OsCounters::from_rusage(&getrusage(libc::RUSAGE_THREAD)))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn thread_counters() -> Option<OsCounters> {
+    None
+}
+
+fn getrusage(who: libc::c_int) -> libc::rusage {
+    unsafe {
+        let mut usage: libc::rusage = std::mem::zeroed();
+        libc::getrusage(who, &mut usage);
+        usage
+    }
+}
+
+/// Runs `func` and returns the OS counters accumulated meanwhile.
+pub fn measure_counters<R, F: FnOnce() -> R>(func: F) -> (R, OsCounters) {
+    let start = OsCounters::snapshot();
+    let result = func();
+    (result, OsCounters::snapshot().since(&start))
+}
+
+/// Measures the OS counters of one phase of a benchmark run, see [`crate::profile::phase`].
+pub(crate) fn measure_phase<R, F: FnOnce() -> R>(phase: Phase, func: F) -> R {
+    let (result, counters) = measure_counters(func);
+    PHASES.lock().unwrap().push((phase, counters));
+    result
+}
+
+/// Returns the OS counters of the phases measured since the last call.
+pub(crate) fn take_phase_stats() -> Vec<(Phase, OsCounters)> {
+    std::mem::take(&mut *PHASES.lock().unwrap())
+}
+
+/// Parses the `<key><separator> <value>` lines of a `/proc` file, or nothing if it is missing.
+fn read_proc_fields(path: &str, separator: char) -> Vec<(String, u64)> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(separator)?;
+            Some((key.trim().to_string(), value.trim().parse().ok()?))
+        })
+        .collect()
+}
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::path::PathBuf;
+
+/// Number of hottest program counters kept in [`InstructionStats::hot_pcs`].
+pub const TOP_PCS: usize = 16;
+
+/// Load mnemonics of the RISC-V (RV32IM) and MIPS instruction sets.
+const LOAD_MNEMONICS: [&str; 11] = [
+    "lb", "lh", "lw", "lbu", "lhu", "lwl", "lwr", "ll", "lwc1", "ld", "lwu",
+];
+/// Store mnemonics of the RISC-V (RV32IM) and MIPS instruction sets.
+const STORE_MNEMONICS: [&str; 9] = ["sb", "sh", "sw", "swl", "swr", "sc", "swc1", "sd", "sdc1"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PcCount {
+    pub pc: u64,
+    pub count: u64,
+}
+
+/// Instruction mix of a single guest execution, normalized across zkVMs.
+///
+/// Only the SP1, Jolt and ZKM hosts write these statistics, as the other zkVMs do not expose
+/// an instruction-level report. Opcode names are lowercased mnemonics so that `lw` from SP1 and
+/// `LW` from Jolt end up in the same bucket.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct InstructionStats {
+    pub total_instructions: u64,
+    pub opcodes: BTreeMap<String, u64>,
+    /// Syscall histogram of SP1 and ZKM. Empty for Jolt, whose trace does not record syscalls.
+    pub syscalls: BTreeMap<String, u64>,
+    /// Counted from the load mnemonics of the opcode histogram, as no backend reports its memory
+    /// accesses: the accesses of precompiles are left out.
+    pub estimated_memory_reads: u64,
+    /// Counted from the store mnemonics of the opcode histogram, as `estimated_memory_reads`.
+    pub estimated_memory_writes: u64,
+    /// Most executed program counters. Only Jolt exposes the trace they are counted from, so the
+    /// field is left out of the statistics of SP1 and ZKM.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hot_pcs: Option<Vec<PcCount>>,
+}
+
+impl InstructionStats {
+    /// Builds the statistics from an opcode and a syscall histogram. Keys may repeat, in which
+    /// case their counts are summed; zero counts are dropped.
+    pub fn from_histograms<O, S>(
+        opcodes: impl IntoIterator<Item = (O, u64)>,
+        syscalls: impl IntoIterator<Item = (S, u64)>,
+    ) -> Self
+    where
+        O: ToString,
+        S: ToString,
+    {
+        let mut stats = InstructionStats {
+            opcodes: histogram(opcodes),
+            syscalls: histogram(syscalls),
+            ..Default::default()
+        };
+
+        for (opcode, count) in &stats.opcodes {
+            stats.total_instructions += count;
+            if LOAD_MNEMONICS.contains(&opcode.as_str()) {
+                stats.estimated_memory_reads += count;
+            } else if STORE_MNEMONICS.contains(&opcode.as_str()) {
+                stats.estimated_memory_writes += count;
+            }
+        }
+
+        stats
+    }
+
+    /// Keeps the `n` most executed program counters out of an execution trace.
+    pub fn with_hot_pcs(mut self, pcs: impl IntoIterator<Item = u64>, n: usize) -> Self {
+        let mut counts: HashMap<u64, u64> = HashMap::new();
+        for pc in pcs {
+            *counts.entry(pc).or_default() += 1;
+        }
+
+        let mut hot_pcs: Vec<PcCount> = counts
+            .into_iter()
+            .map(|(pc, count)| PcCount { pc, count })
+            .collect();
+        hot_pcs.sort_by(|a, b| b.count.cmp(&a.count).then(a.pc.cmp(&b.pc)));
+        hot_pcs.truncate(n);
+
+        self.hot_pcs = Some(hot_pcs);
+        self
+    }
+
+    /// Writes the statistics to `.outputs/traces/<workload>_<zkvm>_<size>.json`, relative to the
+    /// host crate directory. As this runs within a benchmarked run, a failure is only logged.
+    pub fn write(&self, workload: &str, zkvm: &str, size: impl Display) {
+        let path = PathBuf::from(format!(
+            "../.outputs/traces/{}_{}_{}.json",
+            workload, zkvm, size
+        ));
+        let result = std::fs::create_dir_all(path.parent().unwrap())
+            .and_then(|()| Ok(serde_json::to_vec_pretty(self)?))
+            .and_then(|json| std::fs::write(&path, json));
+        if let Err(err) = result {
+            eprintln!(
+                "Could not write instruction stats to {}: {}",
+                path.display(),
+                err
+            );
+        }
+    }
+}
+
+fn histogram<K: ToString>(entries: impl IntoIterator<Item = (K, u64)>) -> BTreeMap<String, u64> {
+    let mut histogram = BTreeMap::new();
+    for (key, count) in entries {
+        if count == 0 {
+            continue;
+        }
+        *histogram.entry(key.to_string().to_lowercase()).or_default() += count;
+    }
+    histogram
+}
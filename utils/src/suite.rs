@@ -0,0 +1,167 @@
+use serde::Deserialize;
+use std::path::Path;
+
+use crate::metadata::{MLP_INPUTS, NOVANET_FIBONACCI_INPUTS};
+
+/// Comma-separated list of sizes, or of parameter sets such as `bytes=32+iterations=10`,
+/// overriding a host's default inputs.
+pub const SIZES_ENV: &str = "BENCH_SIZES";
+/// Number of times each size is benchmarked.
+pub const REPETITIONS_ENV: &str = "BENCH_REPETITIONS";
+/// Directory the result CSV is written to, instead of the host's default location.
+pub const OUTPUT_DIR_ENV: &str = "BENCH_OUTPUT_DIR";
+/// Device profile label, e.g. `laptop` or `mobile`.
+pub const DEVICE_ENV: &str = "BENCH_DEVICE";
+/// When set, sizes that already have `ok` rows in the result CSV are skipped.
+pub const RESUME_ENV: &str = "BENCH_RESUME";
+
+/// A benchmark suite, as declared in `benchmarks.toml`.
+#[derive(Debug, Deserialize)]
+pub struct SuiteManifest {
+    /// Root of the output tree, relative to the repository root.
+    #[serde(default = "default_output_dir")]
+    pub output_dir: String,
+    #[serde(default, rename = "run")]
+    pub runs: Vec<SuiteEntry>,
+}
+
+/// One (zkVM, workload) pair of the suite.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SuiteEntry {
+    pub zkvm: String,
+    pub workload: String,
+    /// Sizes or parameter sets to run. An empty list keeps the host's defaults from
+    /// `utils::metadata`.
+    #[serde(default)]
+    pub sizes: Vec<String>,
+    #[serde(default = "default_repetitions")]
+    pub repetitions: usize,
+    #[serde(default = "default_device")]
+    pub device: String,
+}
+
+fn default_output_dir() -> String {
+    ".outputs/suite".to_string()
+}
+
+fn default_device() -> String {
+    "default".to_string()
+}
+
+fn default_repetitions() -> usize {
+    1
+}
+
+impl SuiteManifest {
+    pub fn from_file(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(toml::from_str(&content)?)
+    }
+}
+
+/// How to launch the host binary of a (zkVM, workload) pair.
+#[derive(Debug, PartialEq, Eq)]
+pub struct HostCommand {
+    /// Crate directory, relative to the repository root. Cargo is run from there so that the
+    /// crate's own `rust-toolchain` file is picked up, e.g. the nightly pinned by Jolt.
+    pub dir: &'static str,
+    /// Shell commands run from `dir` before cargo, in the same shell: building the guest
+    /// programs the host loads, or sourcing the environment of a custom toolchain.
+    pub setup: Option<String>,
+    pub cargo_args: Vec<String>,
+}
+
+/// Returns the host binary benchmarking `workload` on `zkvm`, or why it cannot be run here.
+pub fn host_command(zkvm: &str, workload: &str) -> Result<HostCommand, String> {
+    let dir = zkvm_dir(zkvm).ok_or_else(|| format!("unknown zkVM `{}`", zkvm))?;
+    if zkvm == "noir-provekit" && !cfg!(target_arch = "aarch64") {
+        return Err("ProveKit is only supported on ARM64".to_string());
+    }
+    let bin = match (zkvm, workload) {
+        ("cairo" | "cairo-zero" | "cairo-m" | "miden" | "noir-provekit", "fib") => None,
+        ("nexus" | "novanet" | "pico" | "sp1", "fib") => Some("fib"),
+        ("jolt" | "openvm" | "risczero" | "zkm", "fib") => Some("fibonacci"),
+        ("jolt" | "nexus" | "openvm" | "pico" | "risczero" | "sp1" | "zkm", "sha2") => Some("sha2"),
+        ("jolt" | "nexus" | "openvm" | "pico" | "risczero" | "sp1" | "zkm", "keccak") => {
+            Some("keccak")
+        }
+        ("jolt" | "nexus" | "openvm" | "pico" | "risczero" | "sp1" | "zkm", "merkle") => {
+            Some("merkle")
+        }
+        ("cairo" | "cairo-m" | "miden" | "noir-provekit" | "sp1", "poseidon2") => Some("poseidon2"),
+        ("jolt" | "openvm" | "pico" | "risczero" | "sp1" | "zkm", "ecdsa") => Some("ecdsa"),
+        ("jolt" | "openvm" | "pico" | "risczero" | "sp1" | "zkm", "eip1559") => Some("eip1559"),
+        ("jolt" | "nexus" | "openvm" | "pico" | "risczero" | "sp1" | "zkm", "ed25519") => {
+            Some("ed25519")
+        }
+        ("openvm" | "risczero" | "sp1" | "zkm", "webauthn") => Some("webauthn"),
+        ("openvm" | "risczero" | "sp1" | "zkm", "jwt") => Some("jwt"),
+        ("openvm" | "risczero" | "sp1" | "zkm", "bls") => Some("bls"),
+        ("novanet" | "openvm" | "risczero" | "sp1" | "zkm", "mlp") => Some("mlp"),
+        ("jolt" | "openvm" | "pico" | "risczero" | "sp1" | "zkm", "ethtransfer") => {
+            Some("transfer-eth")
+        }
+        _ => return Err(format!("no host binary for {} / {}", zkvm, workload)),
+    };
+
+    let setup = match (zkvm, bin) {
+        ("cairo", _) => Some("(cd test_data && scarb --profile release build)".to_string()),
+        ("noir-provekit", _) => Some("(cd runner/test_data && nargo compile)".to_string()),
+        // The Pico hosts load the ELF of their guest at runtime, and only some are committed.
+        ("pico", Some(bin)) => Some(format!(
+            "(cd {}-guest && cargo pico build)",
+            if bin == "fib" { "fibonacci" } else { bin }
+        )),
+        ("zkm", _) => Some(". ~/.zkm-toolchain/env".to_string()),
+        _ => None,
+    };
+
+    let mut cargo_args = vec!["run", "--release"];
+    if matches!(zkvm, "pico" | "sp1") {
+        cargo_args.extend(["-p", "host"]);
+    }
+    let mut cargo_args: Vec<String> = cargo_args.into_iter().map(String::from).collect();
+    match (zkvm, bin) {
+        // The NovaNet runner takes its guest and default inputs on the command line.
+        ("novanet", Some(guest)) => {
+            cargo_args
+                .extend(["-p", "runner", "--", "--guest", guest, "--compress"].map(String::from));
+            if guest == "fib" {
+                cargo_args.extend(["--wat", "fib/fib.wat"].map(String::from));
+            }
+            let inputs: Vec<usize> = match guest {
+                "fib" => NOVANET_FIBONACCI_INPUTS.to_vec(),
+                _ => MLP_INPUTS.to_vec(),
+            };
+            cargo_args.push("--benchmark-args".to_string());
+            cargo_args.extend(inputs.iter().map(ToString::to_string));
+        }
+        (_, Some(bin)) => cargo_args.extend(["--bin", bin].map(String::from)),
+        _ => {}
+    }
+
+    Ok(HostCommand {
+        dir,
+        setup,
+        cargo_args,
+    })
+}
+
+fn zkvm_dir(zkvm: &str) -> Option<&'static str> {
+    Some(match zkvm {
+        "cairo" => "cairo",
+        "cairo-zero" => "cairo-zero",
+        "cairo-m" => "cairo-m",
+        "jolt" => "jolt",
+        "miden" => "miden",
+        "nexus" => "nexus",
+        "noir-provekit" => "noir_provekit",
+        "novanet" => "novanet",
+        "openvm" => "openvm",
+        "pico" => "pico",
+        "risczero" => "risczero",
+        "sp1" => "sp1",
+        "zkm" => "zkm",
+        _ => return None,
+    })
+}
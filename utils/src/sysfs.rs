@@ -0,0 +1,122 @@
+use std::path::{Path, PathBuf};
+
+/// Overrides the root of the sysfs tree, `/sys` by default, e.g. to read a fixture tree.
+pub const SYSFS_ROOT_ENV: &str = "BENCH_SYSFS_ROOT";
+
+/// Reads the CPU and thermal attributes of a sysfs tree.
+#[derive(Debug, Clone)]
+pub struct Sysfs {
+    root: PathBuf,
+}
+
+impl Sysfs {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Sysfs { root: root.into() }
+    }
+
+    /// Returns the tree at `$BENCH_SYSFS_ROOT`, defaulting to `/sys`.
+    pub fn from_env() -> Self {
+        Self::new(std::env::var(SYSFS_ROOT_ENV).unwrap_or("/sys".to_string()))
+    }
+
+    /// Reads and trims an attribute, relative to the root.
+    pub fn read(&self, path: impl AsRef<Path>) -> Option<String> {
+        std::fs::read_to_string(self.root.join(path))
+            .ok()
+            .map(|value| value.trim().to_string())
+    }
+
+    /// Returns the directories of the CPUs, e.g. `devices/system/cpu/cpu0`, relative to the root.
+    pub fn cpus(&self) -> Vec<PathBuf> {
+        self.numbered_dirs("devices/system/cpu", "cpu")
+    }
+
+    /// Returns the directories of the thermal zones, relative to the root.
+    pub fn thermal_zones(&self) -> Vec<PathBuf> {
+        self.numbered_dirs("class/thermal", "thermal_zone")
+    }
+
+    /// Returns the `<prefix><n>` entries of `dir`, sorted by name.
+    fn numbered_dirs(&self, dir: &str, prefix: &str) -> Vec<PathBuf> {
+        let mut dirs: Vec<PathBuf> = std::fs::read_dir(self.root.join(dir))
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.ok()?.file_name().into_string().ok()?;
+                let index = name.strip_prefix(prefix)?;
+                (!index.is_empty() && index.chars().all(|c| c.is_ascii_digit()))
+                    .then(|| Path::new(dir).join(&name))
+            })
+            .collect();
+        dirs.sort();
+        dirs
+    }
+}
+
+/// A sysfs tree in a temporary directory, removed when dropped.
+#[cfg(test)]
+pub(crate) struct SysfsFixture {
+    root: PathBuf,
+}
+
+#[cfg(test)]
+impl SysfsFixture {
+    /// Creates an empty tree, named after the test using it so that tests can run in parallel.
+    pub(crate) fn new(name: &str) -> Self {
+        let root = std::env::temp_dir().join(format!("sysfs-{}-{}", std::process::id(), name));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        SysfsFixture { root }
+    }
+
+    /// Writes an attribute, relative to the root, creating its directories.
+    pub(crate) fn write(&self, path: &str, value: &str) {
+        let path = self.root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, format!("{}\n", value)).unwrap();
+    }
+
+    pub(crate) fn sysfs(&self) -> Sysfs {
+        Sysfs::new(&self.root)
+    }
+}
+
+#[cfg(test)]
+impl Drop for SysfsFixture {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.root);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbered_dirs() {
+        let fixture = SysfsFixture::new("numbered_dirs");
+        for cpu in ["cpu1", "cpu0", "cpufreq", "cpuidle", "cpu"] {
+            fixture.write(&format!("devices/system/cpu/{}/online", cpu), "1");
+        }
+        fixture.write("class/thermal/thermal_zone0/temp", "42000");
+        fixture.write("class/thermal/cooling_device0/type", "Processor");
+
+        let sysfs = fixture.sysfs();
+        assert_eq!(
+            sysfs.cpus(),
+            [
+                Path::new("devices/system/cpu/cpu0"),
+                Path::new("devices/system/cpu/cpu1")
+            ]
+        );
+        assert_eq!(
+            sysfs.thermal_zones(),
+            [Path::new("class/thermal/thermal_zone0")]
+        );
+        assert_eq!(
+            sysfs.read("class/thermal/thermal_zone0/temp").as_deref(),
+            Some("42000")
+        );
+        assert_eq!(sysfs.read("class/thermal/thermal_zone1/temp"), None);
+    }
+}
@@ -0,0 +1,199 @@
+use std::path::{Path, PathBuf};
+
+use crate::sysfs::Sysfs;
+
+/// Temperature and frequency of the CPUs over a run. Unavailable readings are left at zero.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ThermalStats {
+    /// Maximum temperature of the thermal zones, in degrees Celsius.
+    pub max_temperature: f64,
+    /// Minimum and average frequency of the cores, in MHz.
+    pub min_frequency: u64,
+    pub avg_frequency: u64,
+    /// Whether the CPUs were throttled: the kernel throttle counters increased, or a thermal zone
+    /// reached one of its own passive trip points.
+    pub throttled: bool,
+}
+
+/// Samples the thermal zones and the core frequencies of a sysfs tree.
+pub struct ThermalMonitor {
+    sysfs: Sysfs,
+    zones: Vec<Zone>,
+    frequencies: Vec<PathBuf>,
+    throttle_counters: Vec<PathBuf>,
+    throttle_start: u64,
+    min_frequency: Option<u64>,
+    frequency_sum: u64,
+    frequency_count: u64,
+}
+
+impl ThermalMonitor {
+    pub fn start(sysfs: Sysfs) -> Self {
+        let cpus = sysfs.cpus();
+        let mut monitor = ThermalMonitor {
+            zones: sysfs
+                .thermal_zones()
+                .iter()
+                .map(|zone| Zone {
+                    temperature: zone.join("temp"),
+                    trip_point: passive_trip_points(&sysfs, zone)
+                        .into_iter()
+                        .reduce(f64::min),
+                    max_temperature: None,
+                })
+                .collect(),
+            frequencies: cpus
+                .iter()
+                .map(|cpu| cpu.join("cpufreq/scaling_cur_freq"))
+                .collect(),
+            throttle_counters: cpus
+                .iter()
+                .flat_map(|cpu| {
+                    [
+                        cpu.join("thermal_throttle/core_throttle_count"),
+                        cpu.join("thermal_throttle/package_throttle_count"),
+                    ]
+                })
+                .collect(),
+            throttle_start: 0,
+            min_frequency: None,
+            frequency_sum: 0,
+            frequency_count: 0,
+            sysfs,
+        };
+        monitor.throttle_start = monitor.throttle_count();
+        monitor
+    }
+
+    pub fn sample(&mut self) {
+        for zone in &mut self.zones {
+            let temperature = self.sysfs.read(&zone.temperature);
+            let Some(temperature) = temperature.and_then(|value| value.parse::<u64>().ok()) else {
+                continue;
+            };
+            // Reported in millidegrees Celsius.
+            let celsius = temperature as f64 / 1000.0;
+            zone.max_temperature =
+                Some(zone.max_temperature.map_or(celsius, |max| max.max(celsius)));
+        }
+        let frequencies: Vec<u64> = self.read_all(&self.frequencies).collect();
+        for frequency in frequencies {
+            self.min_frequency = Some(
+                self.min_frequency
+                    .map_or(frequency, |min| min.min(frequency)),
+            );
+            self.frequency_sum += frequency;
+            self.frequency_count += 1;
+        }
+    }
+
+    pub fn finish(mut self) -> ThermalStats {
+        self.sample();
+        ThermalStats {
+            max_temperature: self
+                .zones
+                .iter()
+                .filter_map(|zone| zone.max_temperature)
+                .reduce(f64::max)
+                .unwrap_or_default(),
+            // Reported in kHz.
+            min_frequency: self.min_frequency.unwrap_or_default() / 1000,
+            avg_frequency: (self.frequency_sum / self.frequency_count.max(1)) / 1000,
+            throttled: self.throttle_count() > self.throttle_start
+                || self.zones.iter().any(Zone::reached_trip_point),
+        }
+    }
+
+    fn throttle_count(&self) -> u64 {
+        self.read_all(&self.throttle_counters).sum()
+    }
+
+    fn read_all<'a>(&'a self, paths: &'a [PathBuf]) -> impl Iterator<Item = u64> + 'a {
+        paths
+            .iter()
+            .filter_map(|path| self.sysfs.read(path)?.parse().ok())
+    }
+}
+
+/// A thermal zone, whose temperature is compared to its own trip point: the zones of a machine
+/// measure different sensors, with trip points of their own.
+struct Zone {
+    temperature: PathBuf,
+    /// Lowest passive trip point of the zone, in degrees Celsius.
+    trip_point: Option<f64>,
+    max_temperature: Option<f64>,
+}
+
+impl Zone {
+    fn reached_trip_point(&self) -> bool {
+        match (self.max_temperature, self.trip_point) {
+            (Some(temperature), Some(trip_point)) => temperature >= trip_point,
+            _ => false,
+        }
+    }
+}
+
+/// Returns the passive trip points of a thermal zone, in degrees Celsius.
+fn passive_trip_points(sysfs: &Sysfs, zone: &Path) -> Vec<f64> {
+    (0..)
+        .map_while(|i| {
+            let kind = sysfs.read(zone.join(format!("trip_point_{}_type", i)))?;
+            let temperature = sysfs.read(zone.join(format!("trip_point_{}_temp", i)));
+            Some((kind, temperature))
+        })
+        .filter(|(kind, _)| kind == "passive")
+        .filter_map(|(_, temperature)| Some(temperature?.parse::<f64>().ok()? / 1000.0))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sysfs::SysfsFixture;
+
+    fn zone(fixture: &SysfsFixture, zone: usize, temperature: u64, trip_point: u64) {
+        let dir = format!("class/thermal/thermal_zone{}", zone);
+        fixture.write(&format!("{}/temp", dir), &temperature.to_string());
+        fixture.write(&format!("{}/trip_point_0_type", dir), "passive");
+        fixture.write(
+            &format!("{}/trip_point_0_temp", dir),
+            &trip_point.to_string(),
+        );
+    }
+
+    #[test]
+    fn throttle_counter_increase() {
+        let fixture = SysfsFixture::new("throttle_counter_increase");
+        let counter = "devices/system/cpu/cpu0/thermal_throttle/core_throttle_count";
+        fixture.write(counter, "3");
+        let monitor = ThermalMonitor::start(fixture.sysfs());
+        fixture.write(counter, "4");
+        assert!(monitor.finish().throttled);
+
+        let monitor = ThermalMonitor::start(fixture.sysfs());
+        assert!(!monitor.finish().throttled);
+    }
+
+    #[test]
+    fn per_zone_trip_point() {
+        let fixture = SysfsFixture::new("per_zone_trip_point");
+        // A hot zone below its trip point, and a cool zone with a lower one.
+        zone(&fixture, 0, 80_000, 95_000);
+        zone(&fixture, 1, 40_000, 50_000);
+        let stats = ThermalMonitor::start(fixture.sysfs()).finish();
+        assert_eq!(stats.max_temperature, 80.0);
+        assert!(!stats.throttled);
+
+        zone(&fixture, 1, 55_000, 50_000);
+        assert!(ThermalMonitor::start(fixture.sysfs()).finish().throttled);
+    }
+
+    #[test]
+    fn missing_cpufreq() {
+        let fixture = SysfsFixture::new("missing_cpufreq");
+        fixture.write("devices/system/cpu/cpu0/online", "1");
+        fixture.write("devices/system/cpu/cpu1/online", "1");
+        let stats = ThermalMonitor::start(fixture.sysfs()).finish();
+        assert_eq!(stats, ThermalStats::default());
+    }
+}
@@ -0,0 +1,130 @@
+use serde::Serialize;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use crate::openmetrics::write_atomic;
+use crate::profile::Phase;
+
+/// Keeps the RSS time series of each run when set, written next to the results as
+/// `<workload>_<zkvm>_<size>_memory.json`.
+pub const TIMELINE_ENV: &str = "BENCH_MEMORY_TIMELINE";
+
+/// Start of the run being recorded, and the phases it went through so far.
+static RECORDING: Mutex<Option<(Instant, Vec<PhaseSpan>)>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RssSample {
+    /// Milliseconds since the start of the run.
+    pub t_ms: f64,
+    pub rss: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseSpan {
+    pub phase: &'static str,
+    pub start_ms: f64,
+    pub end_ms: f64,
+}
+
+/// Memory usage over the course of one run.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct RunTimeline {
+    pub samples: Vec<RssSample>,
+    pub phases: Vec<PhaseSpan>,
+}
+
+/// Memory timelines of the repetitions of one benchmark size.
+#[derive(Debug, Clone, Serialize)]
+pub struct MemoryTimeline {
+    pub workload: String,
+    pub zkvm: String,
+    pub size: String,
+    pub interval_ms: u128,
+    pub runs: Vec<RunTimeline>,
+}
+
+impl MemoryTimeline {
+    pub fn write(&self, path: &Path) -> std::io::Result<()> {
+        let json = serde_json::to_string(self).expect("Failed to serialize memory timeline");
+        write_atomic(path, json.as_bytes())
+    }
+}
+
+pub fn enabled() -> bool {
+    std::env::var(TIMELINE_ENV).is_ok()
+}
+
+/// Starts recording the phases of a run, relative to `origin`.
+pub(crate) fn start(origin: Instant) {
+    *RECORDING.lock().unwrap() = Some((origin, Vec::new()));
+}
+
+/// Stops recording and returns the phases of the run.
+pub(crate) fn finish() -> Vec<PhaseSpan> {
+    RECORDING
+        .lock()
+        .unwrap()
+        .take()
+        .map(|(_, phases)| phases)
+        .unwrap_or_default()
+}
+
+/// Marks the span of one phase of a benchmark run on the timeline being recorded, if any, see
+/// [`crate::profile::phase`].
+pub(crate) fn mark_phase<R, F: FnOnce() -> R>(phase: Phase, func: F) -> R {
+    let Some(origin) = RECORDING
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|(origin, _)| *origin)
+    else {
+        return func();
+    };
+
+    let start_ms = elapsed_ms(origin);
+    let result = func();
+    let span = PhaseSpan {
+        phase: phase.name(),
+        start_ms,
+        end_ms: elapsed_ms(origin),
+    };
+    if let Some((_, phases)) = RECORDING.lock().unwrap().as_mut() {
+        phases.push(span);
+    }
+
+    result
+}
+
+pub(crate) fn elapsed_ms(origin: Instant) -> f64 {
+    origin.elapsed().as_secs_f64() * 1e3
+}
+
+/// Returns the current resident set size of the process, in bytes.
+#[cfg(target_os = "linux")]
+pub fn current_rss() -> Option<usize> {
+    let statm = std::fs::read_to_string("/proc/self/statm").ok()?;
+    let pages: usize = statm.split_whitespace().nth(1)?.parse().ok()?;
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    Some(pages * page_size as usize)
+}
+
+/// Returns the current resident set size of the process, in bytes.
+#[cfg(target_os = "macos")]
+pub fn current_rss() -> Option<usize> {
+    #[allow(deprecated)]
+    let task = unsafe { libc::mach_task_self() };
+    let mut info: libc::mach_task_basic_info = unsafe { std::mem::zeroed() };
+    let mut count = (std::mem::size_of::<libc::mach_task_basic_info>()
+        / std::mem::size_of::<libc::natural_t>())
+        as libc::mach_msg_type_number_t;
+    let res = unsafe {
+        libc::task_info(
+            task,
+            libc::MACH_TASK_BASIC_INFO,
+            &mut info as *mut _ as libc::task_info_t,
+            &mut count,
+        )
+    };
+    (res == libc::KERN_SUCCESS).then_some(info.resident_size as usize)
+}
@@ -3,7 +3,7 @@ use std::path::Path;
 use std::time::Instant;
 use tempfile::NamedTempFile;
 use tmpfile_helper::*;
-use utils::bench::{benchmark, Metrics};
+use utils::bench::{benchmark, Metrics, Target};
 use utils::metadata::FIBONACCI_INPUTS;
 #[cfg(target_arch = "aarch64")]
 use valida_vm_api_linux_arm::*;
@@ -14,7 +14,7 @@ fn main() {
     benchmark(
         bench_fib,
         &FIBONACCI_INPUTS,
-        "../../.outputs/benchmark/fib_valida.csv",
+        Target::from_args("fib", "valida", "../../.outputs/benchmark/fib_valida.csv"),
     );
 }
 
@@ -22,6 +22,7 @@ fn bench_fib(n: u32) -> Metrics {
     let mut metrics = Metrics::new(n as usize);
     let program =
         Path::new("../fibonacci/target/valida-unknown-baremetal-gnu/release/").join("fibonacci");
+    metrics.program_bytes = fs::metadata(&program).unwrap().len() as usize;
 
     let valida = create_valida().unwrap();
 
@@ -0,0 +1,12 @@
+#![no_main]
+
+use guests::bls;
+extern crate alloc;
+
+zkm_zkvm::entrypoint!(main);
+
+pub fn main() {
+    let input: bls::BlsAggregateInput = zkm_zkvm::io::read();
+    let result = bls::verify_aggregate(&input);
+    zkm_zkvm::io::commit::<bool>(&result);
+}
@@ -6,7 +6,7 @@ extern crate alloc;
 zkm_zkvm::entrypoint!(main);
 
 pub fn main() {
-    let input: ecdsa::EcdsaVerifyInput = zkm_zkvm::io::read();
-    let result = ecdsa::ecdsa_verify(input);
+    let input: Vec<ecdsa::EcdsaVerifyInput> = zkm_zkvm::io::read();
+    let result = ecdsa::ecdsa_verify_batch(input);
     zkm_zkvm::io::commit::<bool>(&result);
 }
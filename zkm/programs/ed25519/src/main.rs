@@ -0,0 +1,12 @@
+#![no_main]
+
+use guests::ed25519;
+extern crate alloc;
+
+zkm_zkvm::entrypoint!(main);
+
+pub fn main() {
+    let input: Vec<ed25519::Ed25519VerifyInput> = zkm_zkvm::io::read();
+    let result = ed25519::ed25519_verify_batch(input);
+    zkm_zkvm::io::commit::<bool>(&result);
+}
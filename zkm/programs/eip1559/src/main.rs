@@ -0,0 +1,12 @@
+#![no_main]
+
+use guests::eip1559;
+extern crate alloc;
+
+zkm_zkvm::entrypoint!(main);
+
+pub fn main() {
+    let input: Vec<u8> = zkm_zkvm::io::read();
+    let sender = eip1559::recover_sender(&input).expect("invalid transaction");
+    zkm_zkvm::io::commit::<[u8; 20]>(&sender);
+}
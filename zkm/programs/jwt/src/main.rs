@@ -0,0 +1,12 @@
+#![no_main]
+
+use guests::jwt;
+extern crate alloc;
+
+zkm_zkvm::entrypoint!(main);
+
+pub fn main() {
+    let input: jwt::JwtInput = zkm_zkvm::io::read();
+    let subject = jwt::verify_token(&input).expect("invalid token");
+    zkm_zkvm::io::commit::<String>(&subject);
+}
@@ -0,0 +1,12 @@
+#![no_main]
+
+use guests::keccak;
+extern crate alloc;
+
+zkm_zkvm::entrypoint!(main);
+
+pub fn main() {
+    let input: Vec<u8> = zkm_zkvm::io::read();
+    let result = keccak::keccak(&input);
+    zkm_zkvm::io::commit::<[u8; 32]>(&result);
+}
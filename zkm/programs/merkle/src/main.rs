@@ -0,0 +1,12 @@
+#![no_main]
+
+use guests::merkle;
+extern crate alloc;
+
+zkm_zkvm::entrypoint!(main);
+
+pub fn main() {
+    let input: merkle::MerkleInput = zkm_zkvm::io::read();
+    let result = merkle::verify_batch(&input);
+    zkm_zkvm::io::commit::<bool>(&result);
+}
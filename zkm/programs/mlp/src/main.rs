@@ -0,0 +1,12 @@
+#![no_main]
+
+use guests::mlp;
+extern crate alloc;
+
+zkm_zkvm::entrypoint!(main);
+
+pub fn main() {
+    let input: mlp::MlpInput = zkm_zkvm::io::read();
+    let logits = mlp::infer(&input);
+    zkm_zkvm::io::commit::<[i32; mlp::NUM_LOGITS]>(&logits);
+}
@@ -0,0 +1,12 @@
+#![no_main]
+
+use guests::webauthn;
+extern crate alloc;
+
+zkm_zkvm::entrypoint!(main);
+
+pub fn main() {
+    let input: Vec<webauthn::WebAuthnAssertion> = zkm_zkvm::io::read();
+    let result = webauthn::verify_batch(&input);
+    zkm_zkvm::io::commit::<bool>(&result);
+}
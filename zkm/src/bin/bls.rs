@@ -0,0 +1,15 @@
+use utils::{
+    bench::{benchmark, Target},
+    bls_params,
+};
+use zkm_script::{bench_bls, init_logger};
+
+fn main() {
+    init_logger();
+
+    benchmark(
+        bench_bls,
+        bls_params(&["software"]),
+        Target::from_args("bls", "zkm", "../.outputs/benchmark/bls_zkm.csv"),
+    );
+}
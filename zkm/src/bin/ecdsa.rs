@@ -1,4 +1,7 @@
-use utils::{bench::benchmark, metadata::ECDSA_INPUTS};
+use utils::{
+    bench::{benchmark, Target},
+    metadata::ECDSA_INPUTS,
+};
 use zkm_script::{bench_ecdsa, init_logger};
 
 fn main() {
@@ -7,6 +10,6 @@ fn main() {
     benchmark(
         bench_ecdsa,
         &ECDSA_INPUTS,
-        "../.outputs/benchmark/ecdsa_zkm.csv",
+        Target::from_args("ecdsa", "zkm", "../.outputs/benchmark/ecdsa_zkm.csv"),
     );
 }
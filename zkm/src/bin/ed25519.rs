@@ -0,0 +1,15 @@
+use utils::{
+    bench::{benchmark, Target},
+    metadata::ED25519_INPUTS,
+};
+use zkm_script::{bench_ed25519, init_logger};
+
+fn main() {
+    init_logger();
+
+    benchmark(
+        bench_ed25519,
+        &ED25519_INPUTS,
+        Target::from_args("ed25519", "zkm", "../.outputs/benchmark/ed25519_zkm.csv"),
+    );
+}
@@ -0,0 +1,15 @@
+use utils::{
+    bench::{benchmark, Target},
+    metadata::EIP1559_INPUTS,
+};
+use zkm_script::{bench_eip1559, init_logger};
+
+fn main() {
+    init_logger();
+
+    benchmark(
+        bench_eip1559,
+        &EIP1559_INPUTS,
+        Target::from_args("eip1559", "zkm", "../.outputs/benchmark/eip1559_zkm.csv"),
+    );
+}
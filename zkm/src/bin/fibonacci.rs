@@ -1,4 +1,7 @@
-use utils::{bench::benchmark, metadata::FIBONACCI_INPUTS};
+use utils::{
+    bench::{benchmark, Target},
+    metadata::FIBONACCI_INPUTS,
+};
 use zkm_script::{bench_fibonacci, init_logger};
 
 fn main() {
@@ -7,6 +10,6 @@ fn main() {
     benchmark(
         bench_fibonacci,
         &FIBONACCI_INPUTS,
-        "../.outputs/benchmark/fib_zkm.csv",
+        Target::from_args("fib", "zkm", "../.outputs/benchmark/fib_zkm.csv"),
     );
 }
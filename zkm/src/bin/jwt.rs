@@ -0,0 +1,15 @@
+use utils::{
+    bench::{benchmark, Target},
+    metadata::JWT_INPUTS,
+};
+use zkm_script::{bench_jwt, init_logger};
+
+fn main() {
+    init_logger();
+
+    benchmark(
+        bench_jwt,
+        &JWT_INPUTS,
+        Target::from_args("jwt", "zkm", "../.outputs/benchmark/jwt_zkm.csv"),
+    );
+}
@@ -0,0 +1,15 @@
+use utils::{
+    bench::{benchmark, Target},
+    metadata::KECCAK_INPUTS,
+};
+use zkm_script::{benchmark_keccak, init_logger};
+
+fn main() {
+    init_logger();
+
+    benchmark(
+        benchmark_keccak,
+        &KECCAK_INPUTS,
+        Target::from_args("keccak", "zkm", "../.outputs/benchmark/keccak_zkm.csv"),
+    );
+}
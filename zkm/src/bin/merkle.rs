@@ -0,0 +1,15 @@
+use utils::{
+    bench::{benchmark, Target},
+    merkle_params,
+};
+use zkm_script::{bench_merkle, init_logger};
+
+fn main() {
+    init_logger();
+
+    benchmark(
+        bench_merkle,
+        merkle_params(),
+        Target::from_args("merkle", "zkm", "../.outputs/benchmark/merkle_zkm.csv"),
+    );
+}
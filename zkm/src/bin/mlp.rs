@@ -0,0 +1,15 @@
+use utils::{
+    bench::{benchmark, Target},
+    metadata::MLP_INPUTS,
+};
+use zkm_script::{bench_mlp, init_logger};
+
+fn main() {
+    init_logger();
+
+    benchmark(
+        bench_mlp,
+        &MLP_INPUTS,
+        Target::from_args("mlp", "zkm", "../.outputs/benchmark/mlp_zkm.csv"),
+    );
+}
@@ -1,4 +1,7 @@
-use utils::{bench::benchmark, metadata::SHA2_INPUTS};
+use utils::{
+    bench::{benchmark, Target},
+    metadata::SHA2_INPUTS,
+};
 use zkm_script::{benchmark_sha2, init_logger};
 
 fn main() {
@@ -7,6 +10,6 @@ fn main() {
     benchmark(
         benchmark_sha2,
         &SHA2_INPUTS,
-        "../.outputs/benchmark/sha2_zkm.csv",
+        Target::from_args("sha2", "zkm", "../.outputs/benchmark/sha2_zkm.csv"),
     );
 }
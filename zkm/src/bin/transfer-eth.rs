@@ -1,4 +1,7 @@
-use utils::{bench::benchmark, metadata::ETHTRANSFER_INPUTS};
+use utils::{
+    bench::{benchmark, Target},
+    metadata::ETHTRANSFER_INPUTS,
+};
 use zkm_script::{bench_ethtransfer, init_logger};
 
 fn main() {
@@ -7,6 +10,10 @@ fn main() {
     benchmark(
         bench_ethtransfer,
         &ETHTRANSFER_INPUTS,
-        "../.outputs/benchmark/ethtransfer_zkm.csv",
+        Target::from_args(
+            "ethtransfer",
+            "zkm",
+            "../.outputs/benchmark/ethtransfer_zkm.csv",
+        ),
     );
 }
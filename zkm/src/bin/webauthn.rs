@@ -0,0 +1,15 @@
+use utils::{
+    bench::{benchmark, Target},
+    metadata::WEBAUTHN_INPUTS,
+};
+use zkm_script::{bench_webauthn, init_logger};
+
+fn main() {
+    init_logger();
+
+    benchmark(
+        bench_webauthn,
+        &WEBAUTHN_INPUTS,
+        Target::from_args("webauthn", "zkm", "../.outputs/benchmark/webauthn_zkm.csv"),
+    );
+}
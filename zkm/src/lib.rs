@@ -1,13 +1,29 @@
 use std::time::Instant;
 
-use utils::{bench::Metrics, ecdsa_input, sha2_input, size};
+use utils::{
+    bench::Metrics,
+    bls_input, bls_precompile, ecdsa_input, ed25519_input, eip1559_input, eip1559_sender,
+    jwt_input, jwt_subject, keccak_digest, keccak_input, merkle_input, mlp_input, mlp_logits,
+    params::{ParamSet, Params},
+    profile::{phase, Phase},
+    sha2_input, size,
+    stats::InstructionStats,
+    webauthn_input,
+};
 use zkm_build::include_elf;
-use zkm_sdk::{ProverClient, ZKMStdin};
+use zkm_sdk::{ProverClient, ZKMPublicValues, ZKMStdin};
 
 const FIBONACCI_ELF: &[u8] = include_elf!("fibonacci");
 const SHA2_ELF: &[u8] = include_elf!("sha2-bench");
-const SHA3_ELF: &[u8] = include_elf!("sha3-bench");
+const KECCAK_ELF: &[u8] = include_elf!("keccak-bench");
+const MERKLE_ELF: &[u8] = include_elf!("merkle-bench");
+const EIP1559_ELF: &[u8] = include_elf!("eip1559-bench");
 const ECDSA_ELF: &[u8] = include_elf!("ecdsa-bench");
+const ED25519_ELF: &[u8] = include_elf!("ed25519-bench");
+const WEBAUTHN_ELF: &[u8] = include_elf!("webauthn-bench");
+const JWT_ELF: &[u8] = include_elf!("jwt-bench");
+const BLS_ELF: &[u8] = include_elf!("bls-bench");
+const MLP_ELF: &[u8] = include_elf!("mlp-bench");
 const ETHTRANSFER_ELF: &[u8] = include_elf!("transfer-eth");
 
 pub fn init_logger() {
@@ -15,60 +31,162 @@ pub fn init_logger() {
     zkm_core_machine::utils::setup_logger();
 }
 
-fn bench_zkm(elf: &[u8], stdin: ZKMStdin, size_label: usize) -> Metrics {
-    let mut metrics: Metrics = Metrics::new(size_label);
+fn bench_zkm(elf: &[u8], stdin: ZKMStdin, workload: &str, params: impl ParamSet) -> Metrics {
+    bench_zkm_with_output(elf, stdin, workload, params).0
+}
+
+/// Same as [`bench_zkm`], also returning the public values committed by the execution.
+fn bench_zkm_with_output(
+    elf: &[u8],
+    stdin: ZKMStdin,
+    workload: &str,
+    params: impl ParamSet,
+) -> (Metrics, ZKMPublicValues) {
+    let params = params.to_params();
+    let mut metrics: Metrics = Metrics::new(params.clone());
 
-    let client = ProverClient::cpu();
-    let (pk, vk) = client.setup(elf);
+    let client = phase(Phase::Init, || ProverClient::cpu());
+    let (pk, vk) = phase(Phase::Setup, || client.setup(elf));
+    metrics.pk_bytes = size(&pk);
+    metrics.vk_bytes = size(&vk);
+    metrics.program_bytes = elf.len();
 
     // Execute the program using the `ProverClient.execute` method, without generating a proof.
     let start = Instant::now();
-    let (_, report) = client.execute(elf, stdin.clone()).run().unwrap();
+    let (output, report) = phase(Phase::Execute, || {
+        client.execute(elf, stdin.clone()).run().unwrap()
+    });
     metrics.exec_duration = start.elapsed();
     metrics.cycles = report.total_instruction_count() as u64;
 
+    InstructionStats::from_histograms(
+        report
+            .opcode_counts
+            .iter()
+            .map(|(opcode, count)| (opcode, *count)),
+        report
+            .syscall_counts
+            .iter()
+            .map(|(syscall, count)| (format!("{:?}", syscall), *count)),
+    )
+    .write(workload, "zkm", &params);
+
     let start = Instant::now();
-    let proof = client.prove(&pk, stdin.clone()).run().unwrap();
+    let proof = phase(Phase::Prove, || {
+        client.prove(&pk, stdin.clone()).run().unwrap()
+    });
     metrics.proof_duration = start.elapsed();
     metrics.proof_bytes = size(&proof);
 
     // Sometimes the verification is failed with commitment error.
     let start = Instant::now();
-    client.verify(&proof, &vk).expect("verification failed");
+    phase(Phase::Verify, || {
+        client.verify(&proof, &vk).expect("verification failed")
+    });
     metrics.verify_duration = start.elapsed();
 
-    metrics
+    (metrics, output)
 }
 
 pub fn benchmark_sha2(num_bytes: usize) -> Metrics {
     let input = sha2_input(num_bytes);
     let mut stdin = ZKMStdin::new();
     stdin.write(&input);
-    bench_zkm(SHA2_ELF, stdin, num_bytes)
+    bench_zkm(SHA2_ELF, stdin, "sha2", num_bytes)
+}
+
+pub fn benchmark_keccak(num_bytes: usize) -> Metrics {
+    let input = keccak_input(num_bytes);
+    let mut stdin = ZKMStdin::new();
+    stdin.write(&input);
+    let (metrics, mut output) = bench_zkm_with_output(KECCAK_ELF, stdin, "keccak", num_bytes);
+    assert_eq!(output.read::<[u8; 32]>(), keccak_digest(&input));
+    metrics
+}
+
+pub fn bench_merkle(params: Params) -> Metrics {
+    let input = merkle_input(&params);
+    let mut stdin = ZKMStdin::new();
+    stdin.write(&input);
+    let (metrics, mut output) = bench_zkm_with_output(MERKLE_ELF, stdin, "merkle", params);
+    assert!(output.read::<bool>(), "invalid inclusion proofs");
+    metrics
+}
+
+pub fn bench_eip1559(calldata_bytes: usize) -> Metrics {
+    let input = eip1559_input(calldata_bytes);
+    let mut stdin = ZKMStdin::new();
+    stdin.write(&input);
+    let (metrics, mut output) =
+        bench_zkm_with_output(EIP1559_ELF, stdin, "eip1559", calldata_bytes);
+    assert_eq!(output.read::<[u8; 20]>(), eip1559_sender(calldata_bytes));
+    metrics
 }
 
-pub fn benchmark_sha3(num_bytes: usize) -> Metrics {
-    let input = vec![5u8; num_bytes];
+pub fn bench_jwt(payload_bytes: usize) -> Metrics {
+    let input = jwt_input(payload_bytes);
     let mut stdin = ZKMStdin::new();
     stdin.write(&input);
-    bench_zkm(SHA3_ELF, stdin, num_bytes)
+    let (metrics, mut output) = bench_zkm_with_output(JWT_ELF, stdin, "jwt", payload_bytes);
+    assert_eq!(output.read::<String>(), jwt_subject(payload_bytes));
+    metrics
 }
 
 pub fn bench_fibonacci(n: u32) -> Metrics {
     let mut stdin = ZKMStdin::new();
     stdin.write(&n);
-    bench_zkm(FIBONACCI_ELF, stdin, n as usize)
+    bench_zkm(FIBONACCI_ELF, stdin, "fib", n as usize)
 }
 
 pub fn bench_ecdsa(n: usize) -> Metrics {
-    let input = ecdsa_input();
+    let input = ecdsa_input(n);
     let mut stdin = ZKMStdin::new();
     stdin.write(&input);
-    bench_zkm(ECDSA_ELF, stdin, n)
+    bench_zkm(ECDSA_ELF, stdin, "ecdsa", n)
+}
+
+pub fn bench_ed25519(n: usize) -> Metrics {
+    let input = ed25519_input(n);
+    let mut stdin = ZKMStdin::new();
+    stdin.write(&input);
+    let (metrics, mut output) = bench_zkm_with_output(ED25519_ELF, stdin, "ed25519", n);
+    assert!(output.read::<bool>(), "invalid signatures");
+    metrics
+}
+
+pub fn bench_webauthn(n: usize) -> Metrics {
+    let input = webauthn_input(n);
+    let mut stdin = ZKMStdin::new();
+    stdin.write(&input);
+    let (metrics, mut output) = bench_zkm_with_output(WEBAUTHN_ELF, stdin, "webauthn", n);
+    assert!(output.read::<bool>(), "invalid assertions");
+    metrics
+}
+
+pub fn bench_bls(params: Params) -> Metrics {
+    assert!(
+        !bls_precompile(&params),
+        "ZKM only runs the BLS pairings in software"
+    );
+    let input = bls_input(&params);
+    let mut stdin = ZKMStdin::new();
+    stdin.write(&input);
+    let (metrics, mut output) = bench_zkm_with_output(BLS_ELF, stdin, "bls", params);
+    assert!(output.read::<bool>(), "invalid aggregate signature");
+    metrics
+}
+
+pub fn bench_mlp(width: usize) -> Metrics {
+    let input = mlp_input(width);
+    let mut stdin = ZKMStdin::new();
+    stdin.write(&input);
+    let (metrics, mut output) = bench_zkm_with_output(MLP_ELF, stdin, "mlp", width);
+    assert_eq!(output.read::<[i32; 10]>(), mlp_logits(width));
+    metrics
 }
 
 pub fn bench_ethtransfer(n: usize) -> Metrics {
     let mut stdin = ZKMStdin::new();
     stdin.write(&n);
-    bench_zkm(ETHTRANSFER_ELF, stdin, n)
+    bench_zkm(ETHTRANSFER_ELF, stdin, "ethtransfer", n)
 }